@@ -0,0 +1,61 @@
+//! Alternative implementations that coexist in the days, measured against
+//! each other. Only the examples are used: both the brute force of day 12
+//! and the recursive flood fill of day 10 don't scale to the full inputs.
+
+use std::fs;
+
+use aoc::inputs;
+use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day10::{Day10, FloodFill};
+use day12::Day12;
+
+/// `(name, contents)` of all the examples of `day`.
+fn examples(day: u8) -> Vec<(String, String)> {
+    inputs::example_paths(day)
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_owned();
+            Some((name, fs::read_to_string(&path).ok()?))
+        })
+        .collect()
+}
+
+fn day10_flood_fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day10/flood_fill");
+
+    for (name, input) in examples(Day10::DAY) {
+        let Ok(board) = Day10::parse(&input) else {
+            continue;
+        };
+        for fill in [FloodFill::Iterative, FloodFill::Recursive] {
+            let id = BenchmarkId::new(format!("{fill:?}").to_lowercase(), &name);
+            group.bench_function(id, |b| {
+                b.iter(|| day10::count_inside(black_box(&board), fill))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn day12_combinations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day12/combinations");
+
+    for (name, input) in examples(Day12::DAY) {
+        let Ok(rows) = Day12::parse(&input) else {
+            continue;
+        };
+        group.bench_function(BenchmarkId::new("brute_force", &name), |b| {
+            b.iter(|| day12::num_of_combinations(black_box(&rows)))
+        });
+        group.bench_function(BenchmarkId::new("dynamic", &name), |b| {
+            b.iter(|| day12::num_of_combinations_dynamic(black_box(&rows)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day10_flood_fill, day12_combinations);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Parse, part 1 and part 2 of every day, on all of its examples and on
+//! the full input (when `dayNN/input.txt` is there).
+//!
+//! `cargo bench -p aoc --bench days -- day07` runs a single day.
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::{inputs, DAYS};
+use aoc_common::{Day, Part};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// `(name, path)` of every input of `day` that can be read.
+fn bench_inputs(day: u8) -> Vec<(String, String)> {
+    let full = inputs::input_path(day);
+    let paths: Vec<PathBuf> = inputs::example_paths(day)
+        .into_iter()
+        .chain(full.exists().then_some(full))
+        .collect();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_owned();
+            Some((name, fs::read_to_string(&path).ok()?))
+        })
+        .collect()
+}
+
+fn bench_day(c: &mut Criterion, day: &Day) {
+    let mut group = c.benchmark_group(format!("day{:02}", day.day));
+
+    for (name, input) in bench_inputs(day.day) {
+        group.bench_with_input(BenchmarkId::new("parse", &name), &input, |b, input| {
+            b.iter(|| day.parse(black_box(input)))
+        });
+
+        // inputs that don't parse or don't have an answer (e.g. the examples
+        // of only one of the parts) are skipped instead of measuring errors
+        let Ok(parsed) = day.parse(&input) else {
+            continue;
+        };
+        for part in Part::ALL {
+            if parsed.solve(part).is_err() {
+                continue;
+            }
+            group.bench_function(BenchmarkId::new(format!("part{part}"), &name), |b| {
+                b.iter(|| parsed.solve(black_box(part)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    for day in &DAYS {
+        bench_day(c, day);
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
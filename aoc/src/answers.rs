@@ -0,0 +1,98 @@
+//! Accepted answers, stored next to the hash of the input they belong to,
+//! so that a refactor which changes any of them gets noticed.
+//!
+//! The file has one answer per line: `day part hash answer`, e.g.
+//! `03 1 5b1c1f8a92d4e0f7 4361`. Lines starting with `#` are comments.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::Part;
+
+const HEADER: &str = "# day part input-hash answer\n";
+
+/// `answers.txt` at the root of the workspace.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.txt")
+}
+
+/// 64-bit FNV-1a of the input, stable across platforms and Rust versions.
+pub fn input_hash(input: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    input.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, Part, u64), String>,
+}
+
+impl Answers {
+    /// Reads the answers from `path`; a missing file has no answers yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn parse(contents: &str) -> io::Result<Self> {
+        let mut answers = Self::default();
+
+        for (n, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                let message = format!("line {}: expected `day part hash answer`", n + 1);
+                io::Error::new(io::ErrorKind::InvalidData, message)
+            };
+            let mut fields = line.splitn(4, ' ');
+            let mut field = || fields.next().ok_or_else(invalid);
+
+            let day = field()?.parse().map_err(|_| invalid())?;
+            let part = field()?
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(invalid)?;
+            let hash = u64::from_str_radix(field()?, 16).map_err(|_| invalid())?;
+            let answer = field()?.trim();
+
+            answers.record(day, part, hash, answer);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = String::from(HEADER);
+        for ((day, part, hash), answer) in &self.entries {
+            // writing to a `String` never fails
+            let _ = writeln!(contents, "{day:02} {part} {hash:016x} {answer}");
+        }
+
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u8, part: Part, hash: u64) -> Option<&str> {
+        self.entries.get(&(day, part, hash)).map(String::as_str)
+    }
+
+    /// Records `answer`, replacing the previous one for the same input.
+    pub fn record(&mut self, day: u8, part: Part, hash: u64, answer: &str) {
+        self.entries.insert((day, part, hash), answer.to_owned());
+    }
+}
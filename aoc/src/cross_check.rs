@@ -0,0 +1,66 @@
+//! Runs the naive and the optimised algorithms of a day side by side and
+//! shrinks the inputs they disagree on.
+
+use aoc_common::{Checker, Comparison, Error};
+
+/// A comparison that failed, together with the smallest input (found by
+/// [`minimise`]) that still makes it fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub comparison: Comparison,
+    pub input: String,
+}
+
+/// The comparison called `name` if it disagrees on `input` (which has to
+/// still parse).
+fn disagreement(checker: &Checker, input: &str, name: &str) -> Option<Comparison> {
+    checker
+        .check(input)
+        .ok()?
+        .into_iter()
+        .find(|comparison| comparison.name == name && !comparison.agrees())
+}
+
+/// Greedily drops lines of `input` for as long as the comparison called
+/// `name` keeps disagreeing, so no single line can be removed from the result.
+pub fn minimise(checker: &Checker, input: &str, name: &str) -> Disagreement {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut comparison =
+        disagreement(checker, input, name).expect("Only disagreeing inputs can be minimised");
+
+    let mut i = 0;
+    while i < lines.len() {
+        let mut candidate = lines.clone();
+        candidate.remove(i);
+        let text = candidate.join("\n") + "\n";
+
+        match disagreement(checker, &text, name) {
+            Some(smaller) => {
+                lines = candidate;
+                comparison = smaller;
+            }
+            None => i += 1,
+        }
+    }
+
+    Disagreement {
+        comparison,
+        input: lines.join("\n") + "\n",
+    }
+}
+
+/// Runs all the comparisons of `checker` on `input`, minimising the input
+/// of every one that disagrees.
+pub fn check(
+    checker: &Checker,
+    input: &str,
+) -> Result<(Vec<Comparison>, Vec<Disagreement>), Error> {
+    let comparisons = checker.check(input)?;
+    let disagreements = comparisons
+        .iter()
+        .filter(|comparison| !comparison.agrees())
+        .map(|comparison| minimise(checker, input, comparison.name))
+        .collect();
+
+    Ok((comparisons, disagreements))
+}
@@ -0,0 +1,148 @@
+//! Downloading the puzzle inputs, from the Advent of Code server or any
+//! stand-in for it, into a cache so that every input is downloaded once.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const YEAR: u16 = 2023;
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Who's asking, as the server asks automated tools to say
+const USER_AGENT: &str = "github.com/julianuziemblo/advent-of-code-2023 (aoc runner)";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `.aoc/` at the root of the workspace, kept out of git: the cache and the
+/// session token, unless they're set in the environment.
+pub fn config_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(".aoc")
+}
+
+/// Where the inputs come from and where they're kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache: PathBuf,
+    year: u16,
+}
+
+impl Fetcher {
+    /// Downloads from `base_url` (e.g. [`DEFAULT_URL`]) into `cache`, with
+    /// no session token yet.
+    pub fn new(base_url: impl Into<String>, cache: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: None,
+            cache: cache.into(),
+            year: YEAR,
+        }
+    }
+
+    /// Configured by the environment:
+    /// - `AOC_URL`, the server [default: [`DEFAULT_URL`]]
+    /// - `AOC_SESSION`, the `session` cookie of a logged in browser
+    ///   [default: the contents of `.aoc/session`]
+    /// - `AOC_CACHE`, the directory for the inputs [default: `.aoc/inputs`]
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_owned());
+        let cache = env::var_os("AOC_CACHE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| config_dir().join("inputs"));
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(config_dir().join("session")).ok());
+
+        let fetcher = Self::new(base_url, cache);
+        match session {
+            Some(session) => fetcher.session(session),
+            None => fetcher,
+        }
+    }
+
+    pub fn session(mut self, session: impl AsRef<str>) -> Self {
+        let session = session.as_ref().trim();
+        self.session = (!session.is_empty()).then(|| session.to_owned());
+        self
+    }
+
+    pub fn year(mut self, year: u16) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// `<cache>/<year>/dayNN.txt`
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache
+            .join(self.year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{day}/input", self.base_url, self.year)
+    }
+
+    /// The input of the day from the cache, downloading it first if it
+    /// isn't in there yet.
+    pub fn input(&self, day: u8) -> io::Result<String> {
+        let path = self.cache_path(day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            Err(_) => {}
+        }
+
+        let input = self.download(day)?;
+        fs::create_dir_all(path.parent().expect("Cache paths have a parent"))?;
+        // through a temporary file, so that an interrupted write doesn't
+        // leave half an input in the cache
+        let partial = path.with_extension("part");
+        fs::write(&partial, &input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(input)
+    }
+
+    fn download(&self, day: u8) -> io::Result<String> {
+        let Some(session) = &self.session else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "no input in `{}`, and no session token to download it with \
+                     (set `AOC_SESSION` or put it into `{}`)",
+                    self.cache_path(day).display(),
+                    config_dir().join("session").display()
+                ),
+            ));
+        };
+
+        let url = self.url(day);
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(TIMEOUT)
+            .build();
+        let response = agent
+            .get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(404, _) => io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("`{url}`: not unlocked yet"),
+                ),
+                ureq::Error::Status(400 | 401 | 500, _) => io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("`{url}`: refused, the session token may have expired"),
+                ),
+                err => io::Error::other(format!("`{url}`: {err}")),
+            })?;
+
+        response.into_string()
+    }
+}
@@ -0,0 +1,46 @@
+//! Where the puzzle inputs of every day live in the workspace.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::fetch::Fetcher;
+
+/// `dayNN/` in the workspace, regardless of the current directory.
+pub fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+}
+
+/// The full puzzle input, `dayNN/input.txt`.
+pub fn input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+/// The full puzzle input: `dayNN/input.txt` if it's there, otherwise the
+/// one the fetcher has cached, or downloads.
+pub fn full_input(day: u8, fetcher: &Fetcher) -> io::Result<String> {
+    match fs::read_to_string(input_path(day)) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => fetcher.input(day),
+        result => result,
+    }
+}
+
+/// All the example inputs (`dayNN/test*.txt`), sorted by name.
+pub fn example_paths(day: u8) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(day_dir(day)) else {
+        return vec![];
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path.file_name().and_then(|name| name.to_str());
+            name.is_some_and(|name| name.starts_with("test") && name.ends_with(".txt"))
+        })
+        .collect();
+    paths.sort();
+
+    paths
+}
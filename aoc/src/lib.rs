@@ -0,0 +1,22 @@
+//! Everything that needs to know about all the days at once.
+
+pub mod answers;
+pub mod cross_check;
+pub mod fetch;
+pub mod inputs;
+pub mod registry;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod viewer;
+pub mod watch;
+
+pub use answers::Answers;
+pub use cross_check::Disagreement;
+pub use fetch::Fetcher;
+pub use registry::{
+    find_animator, find_checker, find_day, find_generator, find_renderer, ANIMATORS, CHECKERS,
+    DAYS, GENERATORS, RENDERERS,
+};
+pub use report::{Record, Report};
+pub use watch::Snapshot;
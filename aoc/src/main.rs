@@ -0,0 +1,766 @@
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc::{
+    answers, cross_check, find_animator, find_checker, find_day, find_generator, find_renderer,
+    inputs, render, report, scaffold, viewer, watch, Answers, Fetcher, Record, Report, Snapshot,
+    DAYS,
+};
+use aoc_common::{Day, Part, Renderer, Size};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use day01::{
+    annotate, calibration_sum_parallel, calibration_sum_reader, parse_words, Calibrate, Combine,
+    Language, Numbers, Recogniser, Summary, DIGITS,
+};
+use log::LevelFilter;
+use rayon::prelude::*;
+
+/// Advent of Code has 25 days, solved or not
+const LAST_DAY: i64 = 25;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the days are doing: `-v` for debug, `-vv` for trace.
+    /// `RUST_LOG` (e.g. `RUST_LOG=day10=trace`) narrows it down further
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `Day NN, part P: answer` lines
+    Text,
+    /// A report of `{day, part, answer, parse_ms, solve_ms}` records
+    Json,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+    day: u8,
+    /// Solve only this part (both by default)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input file, `-` for stdin [default: dayNN/input.txt, or the
+    /// downloaded one]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Accept the answers, storing them in the answers file
+    #[arg(long)]
+    record: bool,
+    /// Answers file [default: answers.txt in the workspace]
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Instead of solving, compare the naive and the optimised algorithms
+    /// of the day, printing the smallest input they disagree on
+    #[arg(long, conflicts_with_all = ["part", "format", "record"])]
+    cross_check: bool,
+    /// Also draw what the day found: an `.svg` or `.png` file, coloured
+    /// text for any other file, or `-` for the terminal
+    #[arg(long, value_name = "PATH", conflicts_with = "cross_check")]
+    render: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CalibrateArgs {
+    /// Puzzle input file, `-` for stdin [default: day01/input.txt, or the
+    /// downloaded one]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Languages of the number words, e.g. `polish,german` or `pl,de`
+    /// [default: english, unless there are `--words`]
+    #[arg(short, long, value_delimiter = ',')]
+    language: Vec<Language>,
+    /// File of more words, a word and its digit on every line
+    #[arg(short, long)]
+    words: Option<PathBuf>,
+    /// Find the words in upper or mixed case too
+    #[arg(long)]
+    ignore_case: bool,
+    /// Show every line with its words marked, `^` under the first and the
+    /// last one, and point out the lines without any
+    #[arg(long)]
+    report: bool,
+    /// Calibrate chunks of the input on this many threads while reading
+    /// the rest (one line at a time on this thread by default)
+    #[arg(short = 'j', long, conflicts_with = "report")]
+    threads: Option<NonZeroUsize>,
+    /// Make the values of whole numbers instead of digits: numerals and
+    /// English number words, like `1905` or `twenty-three`
+    #[arg(long, conflicts_with_all = ["language", "words", "report"])]
+    numbers: bool,
+    /// How the first and the last number make the value, `tens` for
+    /// `first * 10 + last`, `concat` for their digits one after the other
+    #[arg(long, default_value_t, requires = "numbers")]
+    combine: Combine,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day
+    Run(RunArgs),
+    /// Solve all the days that have an input, printing a table of answers
+    All {
+        /// Solve the days in parallel
+        #[arg(short = 'j', long)]
+        parallel: bool,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Re-solve the days and compare the answers with the recorded ones
+    Verify {
+        /// Verify only this day (all the days with an input by default)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+        day: Option<u8>,
+        /// Answers file [default: answers.txt in the workspace]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Step through the algorithms of a day in the terminal
+    Animate {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+        day: u8,
+        /// Puzzle input file, `-` for stdin [default: dayNN/input.txt, or the
+        /// downloaded one]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Sum the calibration values of day 1 with the number words of other
+    /// languages, or with your own words
+    Calibrate(CalibrateArgs),
+    /// Re-solve a day whenever its code or its input changes, comparing the
+    /// answers and the timings with the run before
+    Watch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+        day: u8,
+        /// Solve only this part (both by default)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file [default: dayNN/input.txt, or the downloaded one]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Build optimised, for timings closer to `aoc run --release`
+        #[arg(long)]
+        release: bool,
+    },
+    /// Generate a random, well-formed input for a day
+    Gen {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+        day: u8,
+        /// The same seed always gives the same input [default: a random one]
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// Roughly the number of lines (games, races, patterns, ...)
+        #[arg(short, long, default_value_t = Size::default().count)]
+        count: usize,
+        /// Roughly how long every line (game, race, pattern, ...) is
+        #[arg(short, long, default_value_t = Size::default().length)]
+        length: usize,
+        /// Where to write the input [default: stdout]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Download the inputs into the cache, from `AOC_URL` with the session
+    /// token in `AOC_SESSION` (or `.aoc/session`) into `AOC_CACHE` (or
+    /// `.aoc/inputs`)
+    Fetch {
+        /// Download only this day (all the solved days by default)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+        day: Option<u8>,
+    },
+    /// Start a day: create its crate from a template and add it to the
+    /// workspace and to the runner
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=LAST_DAY))]
+        day: u8,
+    },
+}
+
+fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path)
+}
+
+/// The given input file, or else the full input of the day, wherever it is
+fn day_input(day: u8, path: Option<PathBuf>) -> Result<String, String> {
+    match path {
+        Some(path) => read_input(&path)
+            .map_err(|err| format!("Couldn't read input `{}`: {err}", path.display())),
+        None => inputs::full_input(day, &Fetcher::from_env())
+            .map_err(|err| format!("Couldn't get the input of day {day}: {err}")),
+    }
+}
+
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    Answers::load(path).map_err(|err| format!("Couldn't read answers `{}`: {err}", path.display()))
+}
+
+fn print_report(report: &Report, format: Format) {
+    match format {
+        Format::Text => {
+            for record in &report.records {
+                let day = record.day;
+                let name = match record.part {
+                    Some(part) => format!("Day {day:02}, part {part}"),
+                    None => format!("Day {day:02}"),
+                };
+                match (&record.answer, &record.error) {
+                    (_, Some(err)) => eprintln!("{name}: {err}"),
+                    (Some(answer), None) => println!("{name}: {answer}"),
+                    (None, None) => {}
+                }
+            }
+        }
+        Format::Json => {
+            let json = serde_json::to_string_pretty(report).expect("Reports are always valid JSON");
+            println!("{json}");
+        }
+    }
+}
+
+fn run_cross_check(day: u8, input: &str) -> Result<ExitCode, String> {
+    let checker = find_checker(day)
+        .ok_or_else(|| format!("Day {day} has no alternative algorithms to cross-check"))?;
+    let (comparisons, disagreements) =
+        cross_check::check(checker, input).map_err(|err| format!("Day {day:02}: {err}"))?;
+
+    for comparison in &comparisons {
+        let verdict = if comparison.agrees() {
+            "ok"
+        } else {
+            "DISAGREE"
+        };
+        println!("Day {day:02}, {comparison}: {verdict}");
+    }
+
+    if disagreements.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+    for disagreement in &disagreements {
+        println!();
+        println!("Smallest input for {}:", disagreement.comparison);
+        print!("{}", disagreement.input);
+    }
+
+    Ok(ExitCode::FAILURE)
+}
+
+/// Draws onto the terminal for `-`, through stderr when stdout is taken by
+/// the JSON report
+fn render_into(
+    renderer: &Renderer,
+    input: &str,
+    path: &Path,
+    format: Format,
+) -> Result<(), String> {
+    let day = renderer.day;
+    let picture = renderer
+        .render(input)
+        .map_err(|err| format!("Day {day:02}: {err}"))?;
+
+    if path == Path::new("-") && format == Format::Json {
+        eprint!("{}", render::ansi(&picture));
+        return Ok(());
+    }
+    render::write(&picture, path)
+        .map_err(|err| format!("Couldn't render into `{}`: {err}", path.display()))
+}
+
+fn run(args: RunArgs) -> Result<ExitCode, String> {
+    let RunArgs {
+        day,
+        part,
+        input,
+        format,
+        record,
+        answers,
+        cross_check,
+        render,
+    } = args;
+    let input = day_input(day, input)?;
+
+    if cross_check {
+        return run_cross_check(day, &input);
+    }
+
+    let solution = find_day(day).ok_or_else(|| format!("Day {day} is not solved yet"))?;
+    let renderer = match &render {
+        Some(_) => Some(find_renderer(day).ok_or_else(|| format!("Day {day} can't render"))?),
+        None => None,
+    };
+    let parts = match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let start = Instant::now();
+    let records = report::solve_isolated(solution, &input, &parts);
+    let report = Report::new(records, start.elapsed());
+    print_report(&report, format);
+
+    if record {
+        let answers_path = answers.unwrap_or_else(answers::default_path);
+        let mut answers = load_answers(&answers_path)?;
+        let hash = answers::input_hash(&input);
+
+        for record in &report.records {
+            if let (Some(part), Some(answer)) = (record.part, &record.answer) {
+                let part = Part::from_number(part).expect("Records only have valid parts");
+                answers.record(day, part, hash, answer);
+            }
+        }
+        answers
+            .save(&answers_path)
+            .map_err(|err| format!("Couldn't save answers `{}`: {err}", answers_path.display()))?;
+    }
+
+    if let (Some(renderer), Some(path)) = (renderer, &render) {
+        render_into(renderer, &input, path, format)?;
+    }
+
+    // the errors are already printed as a part of the report
+    if !report.is_ok() {
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_table(report: &Report) {
+    let width = report
+        .records
+        .iter()
+        .filter_map(|record| record.answer.as_ref().map(String::len))
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "Day  Part  {:<width$}  {:>10}  {:>10}",
+        "Answer", "Parse", "Solve"
+    );
+    let mut previous_day = None;
+    let mut total_ms = 0.0;
+    for record in &report.records {
+        // parsing is shared by both parts of a day, so it's counted once
+        let parse_ms = if previous_day == Some(record.day) {
+            0.0
+        } else {
+            record.parse_ms
+        };
+        previous_day = Some(record.day);
+        total_ms += parse_ms + record.solve_ms;
+
+        let part = record.part.map_or("-".to_owned(), |part| part.to_string());
+        let answer = match &record.answer {
+            Some(answer) => answer,
+            None => "FAILED",
+        };
+        println!(
+            "{:02}   {part:<4}  {answer:<width$}  {:>7.3} ms  {:>7.3} ms",
+            record.day, parse_ms, record.solve_ms
+        );
+    }
+
+    println!("Total (sum of all parts): {total_ms:.3} ms");
+    println!("Wall time: {:.3} ms", report.total_ms);
+
+    for record in &report.records {
+        if let Some(err) = &record.error {
+            match record.part {
+                Some(part) => eprintln!("Day {:02}, part {part}: {err}", record.day),
+                None => eprintln!("Day {:02}: {err}", record.day),
+            }
+        }
+    }
+}
+
+fn run_all(parallel: bool, format: Format) -> Result<ExitCode, String> {
+    let fetcher = Fetcher::from_env();
+    let mut days = vec![];
+    for day in &DAYS {
+        match inputs::full_input(day.day, &fetcher) {
+            Ok(input) => days.push((day, input)),
+            Err(err) => eprintln!("Day {:02}: skipped, {err}", day.day),
+        }
+    }
+
+    let solve = |(day, input): &(&Day, String)| report::solve_isolated(day, input, &Part::ALL);
+    let start = Instant::now();
+    let records: Vec<Vec<Record>> = if parallel {
+        days.par_iter().map(solve).collect()
+    } else {
+        days.iter().map(solve).collect()
+    };
+    let report = Report::new(records.concat(), start.elapsed());
+
+    match format {
+        Format::Text => print_table(&report),
+        Format::Json => print_report(&report, format),
+    }
+
+    if !report.is_ok() {
+        let failed: BTreeSet<_> = report
+            .records
+            .iter()
+            .filter(|record| !record.is_ok())
+            .map(|record| format!("{:02}", record.day))
+            .collect();
+        eprintln!("Failed days: {}", Vec::from_iter(failed).join(", "));
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(day: Option<u8>, answers: Option<PathBuf>) -> Result<ExitCode, String> {
+    let answers = load_answers(&answers.unwrap_or_else(answers::default_path))?;
+    let days = DAYS
+        .iter()
+        .filter(|solution| day.is_none_or(|day| solution.day == day));
+
+    let fetcher = Fetcher::from_env();
+    let (mut drifted, mut unrecorded, mut failed) = (0, 0, 0);
+    for solution in days {
+        let day = solution.day;
+        let input = match inputs::full_input(day, &fetcher) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Day {day:02}: skipped, {err}");
+                continue;
+            }
+        };
+        let hash = answers::input_hash(&input);
+
+        for record in report::solve_isolated(solution, &input, &Part::ALL) {
+            let (Some(part), Some(answer)) = (record.part, record.answer) else {
+                let err = record.error.unwrap_or_default();
+                match record.part {
+                    Some(part) => println!("Day {day:02}, part {part}: FAILED, {err}"),
+                    None => println!("Day {day:02}: FAILED, {err}"),
+                }
+                failed += 1;
+                continue;
+            };
+            let part = Part::from_number(part).expect("Records only have valid parts");
+
+            match answers.get(day, part, hash) {
+                Some(recorded) if recorded == answer => {
+                    println!("Day {day:02}, part {part}: ok");
+                }
+                Some(recorded) => {
+                    println!(
+                        "Day {day:02}, part {part}: DRIFTED, got {answer}, recorded {recorded}"
+                    );
+                    drifted += 1;
+                }
+                None => {
+                    println!("Day {day:02}, part {part}: {answer} (not recorded)");
+                    unrecorded += 1;
+                }
+            }
+        }
+    }
+
+    if unrecorded > 0 {
+        println!("{unrecorded} answer(s) not recorded yet, accept them with `aoc run --record`");
+    }
+    let mut problems = vec![];
+    if drifted > 0 {
+        problems.push(format!(
+            "{drifted} answer(s) drifted from the recorded ones"
+        ));
+    }
+    if failed > 0 {
+        problems.push(format!("{failed} error(s) instead of answers"));
+    }
+    if !problems.is_empty() {
+        return Err(problems.join(", "));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn animate(day: u8, input: Option<PathBuf>) -> Result<ExitCode, String> {
+    let animator =
+        find_animator(day).ok_or_else(|| format!("Day {day} has nothing to step through"))?;
+    if !io::stdout().is_terminal() {
+        return Err("Stepping through the algorithms needs a terminal".to_owned());
+    }
+
+    let input = day_input(day, input)?;
+    let recordings = animator
+        .animate(&input)
+        .map_err(|err| format!("Day {day:02}: {err}"))?;
+
+    viewer::view(&recordings).map_err(|err| format!("Couldn't show the recordings: {err}"))?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Calibrates the input a line (or a chunk of them) at a time, however big
+fn calibration_stream(
+    reader: impl BufRead,
+    calibrate: &dyn Calibrate,
+    threads: Option<NonZeroUsize>,
+) -> io::Result<u128> {
+    match threads {
+        Some(threads) => calibration_sum_parallel(reader, calibrate, threads),
+        None => calibration_sum_reader(reader, calibrate),
+    }
+}
+
+fn calibrate(args: CalibrateArgs) -> Result<ExitCode, String> {
+    let CalibrateArgs {
+        input,
+        language: mut languages,
+        words,
+        ignore_case,
+        report,
+        threads,
+        numbers,
+        combine,
+    } = args;
+    let own = match &words {
+        Some(path) => {
+            let text = fs::read_to_string(path)
+                .map_err(|err| format!("Couldn't read words `{}`: {err}", path.display()))?;
+            parse_words(&text).map_err(|err| format!("Words `{}`: {err}", path.display()))?
+        }
+        None => vec![],
+    };
+    if languages.is_empty() && words.is_none() {
+        languages.push(Language::English);
+    }
+
+    let vocabulary = DIGITS
+        .into_iter()
+        .chain(
+            languages
+                .iter()
+                .flat_map(|language| language.words().iter().copied()),
+        )
+        .chain(own.iter().map(|(word, value)| (word.as_str(), *value)));
+    let recogniser = if ignore_case {
+        Recogniser::ignoring_case(vocabulary)
+    } else {
+        Recogniser::new(vocabulary)
+    };
+    if !report {
+        let numbers = match (numbers, ignore_case) {
+            (false, _) => None,
+            (true, false) => Some(Numbers::new(combine)),
+            (true, true) => Some(Numbers::ignoring_case(combine)),
+        };
+        let calibrate: &dyn Calibrate = match &numbers {
+            Some(numbers) => numbers,
+            None => &recogniser,
+        };
+        let sum = match &input {
+            Some(path) if path == Path::new("-") => {
+                calibration_stream(io::stdin().lock(), calibrate, threads)
+            }
+            Some(path) => fs::File::open(path)
+                .and_then(|file| calibration_stream(BufReader::new(file), calibrate, threads)),
+            None => calibration_stream(day_input(1, None)?.as_bytes(), calibrate, threads),
+        };
+        let path = input.unwrap_or_else(|| inputs::input_path(1));
+        let sum = sum.map_err(|err| format!("Couldn't calibrate `{}`: {err}", path.display()))?;
+        println!("{sum}");
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let input = day_input(1, input)?;
+    let mut summary = Summary::default();
+    for line in annotate(&input, &recogniser) {
+        println!("{line}");
+        summary.add(&line);
+    }
+    println!("{summary}");
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn watch(
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    release: bool,
+) -> Result<ExitCode, String> {
+    if input.as_deref() == Some(Path::new("-")) {
+        return Err("Can't watch stdin, the input has to be a file".to_owned());
+    }
+
+    let mut args: Vec<OsString> = vec![];
+    if let Some(part) = part {
+        args.extend(["--part".into(), part.to_string().into()]);
+    }
+    let input = match input {
+        Some(path) => {
+            args.extend(["--input".into(), path.clone().into()]);
+            vec![path]
+        }
+        None => vec![inputs::input_path(day), Fetcher::from_env().cache_path(day)],
+    };
+    let paths = watch::watched_paths(day, &input);
+    let root = scaffold::workspace_root();
+
+    let mut snapshot = Snapshot::take(&paths);
+    let mut previous: Option<Report> = None;
+    loop {
+        let report = watch::solve(day, &args, release)
+            .map_err(|err| format!("Couldn't solve day {day}: {err}"))?;
+        match report {
+            Some(report) => {
+                for change in watch::compare(previous.as_ref(), &report) {
+                    println!("{change}");
+                }
+                previous = Some(report);
+            }
+            None => eprintln!("Day {day:02}: no answers this time"),
+        }
+
+        println!("Watching {} files, Ctrl-C to stop", snapshot.len());
+        let changes = watch::wait(&mut snapshot, &paths);
+        println!();
+        for path in &changes {
+            println!(
+                "Changed `{}`",
+                path.strip_prefix(&root).unwrap_or(path).display()
+            );
+        }
+    }
+}
+
+fn generate(
+    day: u8,
+    seed: Option<u64>,
+    size: Size,
+    output: Option<PathBuf>,
+) -> Result<ExitCode, String> {
+    let generator = find_generator(day).ok_or_else(|| format!("Day {day} has no generator"))?;
+    let seed = seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("Seed: {seed}");
+        seed
+    });
+
+    let input = generator.generate(seed, size);
+    match output {
+        Some(path) => fs::write(&path, input)
+            .map_err(|err| format!("Couldn't write input `{}`: {err}", path.display()))?,
+        None => print!("{input}"),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn fetch(day: Option<u8>) -> Result<ExitCode, String> {
+    let fetcher = Fetcher::from_env();
+    let days = match day {
+        Some(day) => vec![day],
+        None => DAYS.iter().map(|day| day.day).collect(),
+    };
+
+    let mut failed = 0;
+    for day in days {
+        match fetcher.input(day) {
+            Ok(_) => println!("Day {day:02}: `{}`", fetcher.cache_path(day).display()),
+            Err(err) => {
+                eprintln!("Day {day:02}: {err}");
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{failed} input(s) couldn't be downloaded"));
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new_day(day: u8) -> Result<ExitCode, String> {
+    let root = scaffold::workspace_root();
+    let paths =
+        scaffold::new_day(&root, day).map_err(|err| format!("Couldn't add day {day}: {err}"))?;
+    for path in &paths {
+        println!(
+            "Wrote `{}`",
+            path.strip_prefix(&root).unwrap_or(path).display()
+        );
+    }
+    println!(
+        "Put the example into `day{day:02}/test.txt` \
+         and its answers into `day{day:02}/tests/examples.rs`"
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Only the days log (each under the name of its crate), warnings by default
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(LevelFilter::Warn);
+    for day in &DAYS {
+        builder.filter_module(day.target(), level);
+    }
+    builder.parse_default_env().init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::All { parallel, format } => run_all(parallel, format),
+        Command::Verify { day, answers } => verify(day, answers),
+        Command::Animate { day, input } => animate(day, input),
+        Command::Calibrate(args) => calibrate(args),
+        Command::Watch {
+            day,
+            part,
+            input,
+            release,
+        } => watch(day, part, input, release),
+        Command::Gen {
+            day,
+            seed,
+            count,
+            length,
+            output,
+        } => generate(day, seed, Size { count, length }, output),
+        Command::Fetch { day } => fetch(day),
+        Command::NewDay { day } => new_day(day),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_common::{Animator, Checker, Day, Generator, Renderer};
+
+/// All the solved days, in order.
+pub const DAYS: [Day; 13] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+];
+
+/// The days that keep both a naive and an optimised algorithm around.
+pub const CHECKERS: [Checker; 3] = [
+    Checker::of::<day08::Day08>(),
+    Checker::of::<day10::Day10>(),
+    Checker::of::<day12::Day12>(),
+];
+
+/// Input generators of the days that have one. `new-day` doesn't add any, a
+/// day gets one once it has a [`Generate`](aoc_common::Generate) impl.
+pub const GENERATORS: [Generator; 13] = [
+    Generator::of::<day01::Day01>(),
+    Generator::of::<day02::Day02>(),
+    Generator::of::<day03::Day03>(),
+    Generator::of::<day04::Day04>(),
+    Generator::of::<day05::Day05>(),
+    Generator::of::<day06::Day06>(),
+    Generator::of::<day07::Day07>(),
+    Generator::of::<day08::Day08>(),
+    Generator::of::<day09::Day09>(),
+    Generator::of::<day10::Day10>(),
+    Generator::of::<day11::Day11>(),
+    Generator::of::<day12::Day12>(),
+    Generator::of::<day13::Day13>(),
+];
+
+/// The days that can draw a picture of what they found.
+pub const RENDERERS: [Renderer; 4] = [
+    Renderer::of::<day03::Day03>(),
+    Renderer::of::<day10::Day10>(),
+    Renderer::of::<day11::Day11>(),
+    Renderer::of::<day13::Day13>(),
+];
+
+/// The days that can record their algorithms step by step.
+pub const ANIMATORS: [Animator; 3] = [
+    Animator::of::<day08::Day08>(),
+    Animator::of::<day10::Day10>(),
+    Animator::of::<day13::Day13>(),
+];
+
+pub fn find_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+pub fn find_checker(day: u8) -> Option<&'static Checker> {
+    CHECKERS.iter().find(|entry| entry.day == day)
+}
+
+pub fn find_generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|entry| entry.day == day)
+}
+
+pub fn find_renderer(day: u8) -> Option<&'static Renderer> {
+    RENDERERS.iter().find(|entry| entry.day == day)
+}
+
+pub fn find_animator(day: u8) -> Option<&'static Animator> {
+    ANIMATORS.iter().find(|entry| entry.day == day)
+}
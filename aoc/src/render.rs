@@ -0,0 +1,169 @@
+//! Drawing [`Picture`]s of the days as terminal art, SVG or PNG.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+use aoc_common::{Picture, Style};
+
+/// Size of a tile in SVG and PNG files, in pixels
+const TILE: usize = 8;
+
+const BACKGROUND: Rgb = Rgb(15, 15, 35);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+pub(crate) fn colour(style: Style) -> Rgb {
+    match style {
+        Style::Empty => Rgb(60, 60, 80),
+        Style::Plain => Rgb(170, 170, 170),
+        Style::Loop => Rgb(0, 204, 0),
+        Style::Inside => Rgb(255, 255, 102),
+        Style::PartNumber => Rgb(102, 204, 255),
+        Style::Symbol => Rgb(255, 102, 102),
+        Style::Gear => Rgb(255, 255, 102),
+        Style::Galaxy => Rgb(255, 255, 102),
+        Style::Expanded => Rgb(90, 90, 180),
+        Style::Reflection => Rgb(102, 204, 255),
+        Style::Smudge => Rgb(255, 102, 102),
+        Style::Frontier => Rgb(255, 153, 51),
+        Style::Visited => Rgb(0, 120, 120),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Characters coloured with ANSI escape codes, for a terminal
+    Ansi,
+    Svg,
+    /// A coloured square per tile, no characters
+    Png,
+}
+
+impl Format {
+    /// Picks the format by the extension, terminal art for anything unknown.
+    pub fn of(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            Some("svg") => Format::Svg,
+            Some("png") => Format::Png,
+            _ => Format::Ansi,
+        }
+    }
+}
+
+pub fn ansi(picture: &Picture) -> String {
+    let mut art = String::new();
+    for row in picture.iter_rows() {
+        for cell in row {
+            match cell.style {
+                Style::Plain => art.push(cell.ch),
+                _ if cell.ch.is_whitespace() => art.push(cell.ch),
+                style => {
+                    let Rgb(r, g, b) = colour(style);
+                    write!(art, "\x1b[38;2;{r};{g};{b}m{}\x1b[0m", cell.ch)
+                        .expect("Writing to a string can't fail");
+                }
+            }
+        }
+        art.push('\n');
+    }
+
+    art
+}
+
+fn escape(ch: char) -> String {
+    match ch {
+        '<' => "&lt;".to_owned(),
+        '>' => "&gt;".to_owned(),
+        '&' => "&amp;".to_owned(),
+        ch => ch.to_string(),
+    }
+}
+
+pub fn svg(picture: &Picture) -> String {
+    let (width, height) = (picture.columns() * TILE, picture.rows() * TILE);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-family=\"monospace\" font-size=\"{TILE}\" text-anchor=\"middle\">\n"
+    );
+    svg.push_str(&format!(
+        "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+        BACKGROUND.hex()
+    ));
+
+    for (point, cell) in picture.cells() {
+        if cell.ch.is_whitespace() {
+            continue;
+        }
+        let (x, y) = (point.j * TILE + TILE / 2, (point.i + 1) * TILE - 1);
+        svg.push_str(&format!(
+            "<text x=\"{x}\" y=\"{y}\" fill=\"{}\">{}</text>\n",
+            colour(cell.style).hex(),
+            escape(cell.ch)
+        ));
+    }
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+/// Fails on an empty picture, which PNG has no way to store
+pub fn png(picture: &Picture) -> io::Result<Vec<u8>> {
+    let (width, height) = (picture.columns() * TILE, picture.rows() * TILE);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in picture.iter_rows() {
+        let mut line = Vec::with_capacity(width * 3);
+        for cell in row {
+            let Rgb(r, g, b) = match cell.style {
+                Style::Empty if cell.ch.is_whitespace() => BACKGROUND,
+                style => colour(style),
+            };
+            for _ in 0..TILE {
+                line.extend([r, g, b]);
+            }
+        }
+        for _ in 0..TILE {
+            pixels.extend(&line);
+        }
+    }
+
+    let mut png = vec![];
+    let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels))
+        .map_err(io::Error::other)?;
+
+    Ok(png)
+}
+
+pub fn encode(picture: &Picture, format: Format) -> io::Result<Vec<u8>> {
+    match format {
+        Format::Ansi => Ok(ansi(picture).into_bytes()),
+        Format::Svg => Ok(svg(picture).into_bytes()),
+        Format::Png => png(picture),
+    }
+}
+
+/// Draws the picture into `path` (its format picked by the extension),
+/// or onto the terminal for `-`.
+pub fn write(picture: &Picture, path: &Path) -> io::Result<()> {
+    if path == Path::new("-") {
+        print!("{}", ansi(picture));
+        return Ok(());
+    }
+
+    std::fs::write(path, encode(picture, Format::of(path))?)
+}
@@ -0,0 +1,123 @@
+//! Answers of the days together with how long it took to get them.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use aoc_common::{Day, Part};
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a single part, or of parsing when the input is invalid
+/// (then `part` is `None`, since none of the parts could run).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+}
+
+impl Record {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// All the records of a run, e.g. of every day at once.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub records: Vec<Record>,
+    pub total_ms: f64,
+}
+
+impl Report {
+    pub fn new(records: Vec<Record>, total: Duration) -> Self {
+        Self {
+            records,
+            total_ms: millis(total),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.records.iter().all(Record::is_ok)
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Runs `f`, turning a panic in it into an error when `isolate` is set.
+fn guarded<T>(isolate: bool, f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    if !isolate {
+        return f();
+    }
+
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown reason".to_owned());
+
+        Err(format!("panicked: {message}"))
+    })
+}
+
+fn records(day: &Day, input: &str, parts: &[Part], isolate: bool) -> Vec<Record> {
+    let start = Instant::now();
+    let parsed = guarded(isolate, || day.parse(input).map_err(|err| err.to_string()));
+    let parse_ms = millis(start.elapsed());
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            return vec![Record {
+                day: day.day,
+                part: None,
+                answer: None,
+                error: Some(err),
+                parse_ms,
+                solve_ms: 0.0,
+            }]
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = guarded(isolate, || {
+                parsed.solve(part).map_err(|err| err.to_string())
+            });
+            let solve_ms = millis(start.elapsed());
+
+            let (answer, error) = match answer {
+                Ok(answer) => (Some(answer), None),
+                Err(err) => (None, Some(err)),
+            };
+            Record {
+                day: day.day,
+                part: Some(part.number()),
+                answer,
+                error,
+                parse_ms,
+                solve_ms,
+            }
+        })
+        .collect()
+}
+
+/// Parses `input` once and solves `parts` of `day` on it, timing both.
+pub fn solve(day: &Day, input: &str, parts: &[Part]) -> Vec<Record> {
+    records(day, input, parts, false)
+}
+
+/// Same as [`solve`], but a panic while parsing or solving a part is turned
+/// into an error record of its own, so that it doesn't take the other parts
+/// and days down with it.
+pub fn solve_isolated(day: &Day, input: &str, parts: &[Part]) -> Vec<Record> {
+    records(day, input, parts, true)
+}
@@ -0,0 +1,252 @@
+//! Adding a new day to the workspace: its crate, made from a template, and
+//! the entries for it in the workspace and the runner.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "day{NN}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = { path = "../common" }
+log = "0.4"
+"#;
+
+const LIB_TEMPLATE: &str = r#"use std::fmt::Display;
+
+use aoc_common::{lines, Error, ParseError, Solution};
+use log::debug;
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(lines(input)
+        .filter(|line| !line.is_blank())
+        .map(|line| line.text.to_owned())
+        .collect())
+}
+
+fn not_solved() -> Error {
+    Error::NoSolution("not solved yet".to_owned())
+}
+
+pub struct Day{NN};
+
+impl Solution for Day{NN} {
+    const DAY: u8 = {N};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<impl Display, Error> {
+        debug!("{} lines", lines.len());
+        Err::<u64, _>(not_solved())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<impl Display, Error> {
+        debug!("{} lines", lines.len());
+        Err::<u64, _>(not_solved())
+    }
+}
+"#;
+
+const EXAMPLES_TEMPLATE: &str = r#"use aoc_common::{Day, Part};
+use day{NN}::Day{NN};
+
+const DAY: Day = Day::of::<Day{NN}>();
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+#[ignore = "the example and its answer aren't filled in yet"]
+fn part1() {
+    assert_eq!(solve(EXAMPLE, Part::One), "");
+}
+
+#[test]
+#[ignore = "the example and its answer aren't filled in yet"]
+fn part2() {
+    assert_eq!(solve(EXAMPLE, Part::Two), "");
+}
+"#;
+
+/// The root of the workspace, regardless of the current directory.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{NN}", &format!("{day:02}"))
+        .replace("{N}", &day.to_string())
+}
+
+fn invalid(path: &Path, message: &str) -> io::Error {
+    let message = format!("`{}`: {message}", path.display());
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Where the contents of `path` are written before being renamed into place
+fn partial(path: &Path) -> PathBuf {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".part");
+    PathBuf::from(partial)
+}
+
+/// Adds `member` to the `members = [...]` list, keeping it sorted.
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+
+    let mut members: Vec<&str> = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+    members.push(member);
+    members.sort_unstable();
+    members.dedup();
+
+    let list = members
+        .iter()
+        .map(|member| format!("    \"{member}\""))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    Some(format!(
+        "{}\n{list}\n{}",
+        &manifest[..start],
+        &manifest[end..]
+    ))
+}
+
+/// Day of a `dayNN = ...` line
+fn day_of(line: &str) -> Option<u8> {
+    let rest = line.strip_prefix("day")?;
+    let (number, rest) = rest.split_at(rest.find(|ch: char| !ch.is_ascii_digit())?);
+    if !rest.trim_start().starts_with('=') {
+        return None;
+    }
+
+    number.parse().ok()
+}
+
+/// Adds the `dayNN` dependency of the runner, in order with the other days.
+fn add_dependency(manifest: &str, day: u8) -> Option<String> {
+    let mut lines: Vec<String> = manifest.lines().map(str::to_owned).collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(n, line)| Some((n, day_of(line)?)))
+        .collect();
+
+    let (last, _) = *days.last()?;
+    let at = days
+        .iter()
+        .find(|&&(_, other)| other > day)
+        .map_or(last + 1, |&(n, _)| n);
+    lines.insert(at, format!("day{day:02} = {{ path = \"../day{day:02}\" }}"));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the day to `DAYS` in the registry, in order, bumping its length.
+fn register(registry: &str, day: u8) -> Option<String> {
+    const HEADER: &str = "pub const DAYS: [Day; ";
+
+    let header = registry.find(HEADER)?;
+    let length_start = header + HEADER.len();
+    let length_end = length_start + registry[length_start..].find(']')?;
+    let length: usize = registry[length_start..length_end].parse().ok()?;
+    let list_start = length_end + registry[length_end..].find('\n')? + 1;
+    let list_end = list_start + registry[list_start..].find("];")?;
+
+    let entry = |day: u8| format!("    Day::of::<day{day:02}::Day{day:02}>(),");
+    let mut entries: Vec<String> = registry[list_start..list_end]
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    entries.push(entry(day));
+    entries.sort_unstable();
+
+    Some(format!(
+        "{}{}{}\n{}",
+        &registry[..length_start],
+        length + 1,
+        &registry[length_end..list_start],
+        entries.join("\n") + "\n" + &registry[list_end..]
+    ))
+}
+
+/// Creates `dayNN/` under `root` from the templates, with an empty example,
+/// and registers it in the workspace and in the runner. Returns the paths
+/// of all the files it created or changed.
+///
+/// Every file is written next to where it goes first, and only renamed into
+/// place once all of them have been written, so failing to write one leaves
+/// the workspace as it was.
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join(format!("day{day:02}"));
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("`{}` already exists", dir.display()),
+        ));
+    }
+
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = fs::read_to_string(&workspace_path)?;
+    let workspace = add_member(&workspace, &format!("day{day:02}"))
+        .ok_or_else(|| invalid(&workspace_path, "no `members = [...]` list"))?;
+
+    let runner_path = root.join("aoc").join("Cargo.toml");
+    let runner = fs::read_to_string(&runner_path)?;
+    let runner = add_dependency(&runner, day)
+        .ok_or_else(|| invalid(&runner_path, "no `dayNN = ...` dependencies"))?;
+
+    let registry_path = root.join("aoc").join("src").join("registry.rs");
+    let registry = fs::read_to_string(&registry_path)?;
+    let registry =
+        register(&registry, day).ok_or_else(|| invalid(&registry_path, "no `DAYS` list"))?;
+
+    let files = [
+        (dir.join("Cargo.toml"), fill(MANIFEST_TEMPLATE, day)),
+        (dir.join("src").join("lib.rs"), fill(LIB_TEMPLATE, day)),
+        (
+            dir.join("tests").join("examples.rs"),
+            fill(EXAMPLES_TEMPLATE, day),
+        ),
+        (dir.join("test.txt"), String::new()),
+        (workspace_path, workspace),
+        (runner_path, runner),
+        (registry_path, registry),
+    ];
+    let written = fs::create_dir_all(dir.join("src"))
+        .and_then(|()| fs::create_dir_all(dir.join("tests")))
+        .and_then(|()| {
+            files
+                .iter()
+                .try_for_each(|(path, contents)| fs::write(partial(path), contents))
+        });
+    if let Err(err) = written {
+        for (path, _) in &files {
+            let _ = fs::remove_file(partial(path));
+        }
+        let _ = fs::remove_dir_all(&dir);
+        return Err(err);
+    }
+    for (path, _) in &files {
+        fs::rename(partial(path), path)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
@@ -0,0 +1,291 @@
+//! Stepping through [`Recording`]s of the days' algorithms in the terminal.
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use aoc_common::{Cell, Picture, Point, Recording, Style};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::{cursor, queue, terminal};
+
+use crate::render::{colour, Rgb};
+
+/// How often the picture moves on while playing
+const TICK: Duration = Duration::from_millis(50);
+/// Steps per tick to choose from with `+`/`-`
+const SPEEDS: [usize; 8] = [1, 2, 5, 10, 50, 100, 500, 1000];
+/// Steps skipped with page up/down
+const JUMP: usize = 100;
+
+const HELP: &str = "space play/pause  \u{2190}/\u{2192} step  PgUp/PgDn \u{00b1}100  \
+                    Home/End  +/- speed  Tab next recording  q quit";
+
+/// A recording at some step: the picture with all the steps up to there
+/// applied, and what they changed, to undo it when stepping backward.
+#[derive(Clone, Debug)]
+pub struct Player<'a> {
+    recording: &'a Recording,
+    picture: Picture,
+    undo: Vec<Vec<(Point, Cell)>>,
+}
+
+impl<'a> Player<'a> {
+    pub fn new(recording: &'a Recording) -> Self {
+        Self {
+            recording,
+            picture: recording.start.clone(),
+            undo: vec![],
+        }
+    }
+
+    pub fn recording(&self) -> &Recording {
+        self.recording
+    }
+
+    pub fn picture(&self) -> &Picture {
+        &self.picture
+    }
+
+    /// Number of steps applied
+    pub fn position(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position() == self.recording.steps.len()
+    }
+
+    /// Label of the step that was applied last
+    pub fn label(&self) -> &str {
+        match self.position() {
+            0 => "start",
+            n => &self.recording.steps[n - 1].label,
+        }
+    }
+
+    /// Tiles the step that was applied last looks at
+    pub fn focus(&self) -> &[Point] {
+        match self.position() {
+            0 => &[],
+            n => &self.recording.steps[n - 1].focus,
+        }
+    }
+
+    /// Applies the next step, `false` if there's none
+    pub fn forward(&mut self) -> bool {
+        let Some(step) = self.recording.steps.get(self.position()) else {
+            return false;
+        };
+
+        let mut previous = Vec::with_capacity(step.changes.len());
+        for &(point, cell) in &step.changes {
+            if let Some(tile) = self.picture.get_mut(point) {
+                previous.push((point, *tile));
+                *tile = cell;
+            }
+        }
+        self.undo.push(previous);
+
+        true
+    }
+
+    /// Undoes the last step, `false` if there's none
+    pub fn backward(&mut self) -> bool {
+        let Some(previous) = self.undo.pop() else {
+            return false;
+        };
+
+        // in reverse, in case a step changed the same tile twice
+        for &(point, cell) in previous.iter().rev() {
+            self.picture[point] = cell;
+        }
+
+        true
+    }
+
+    pub fn seek(&mut self, position: usize) {
+        while self.position() < position && self.forward() {}
+        while self.position() > position && self.backward() {}
+    }
+}
+
+/// Restores the terminal, also when drawing fails halfway through
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        queue!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = queue!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Moves `offset` so that `focus` is in the `size` tiles long view,
+/// centring it if it has to move at all.
+fn follow(offset: usize, focus: usize, size: usize, total: usize) -> usize {
+    if focus >= offset && focus < offset + size {
+        return offset;
+    }
+
+    focus
+        .saturating_sub(size / 2)
+        .min(total.saturating_sub(size))
+}
+
+fn draw(
+    out: &mut impl Write,
+    player: &Player,
+    (top, left): (usize, usize),
+    status: &str,
+) -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let (columns, rows) = (columns as usize, rows as usize);
+    let picture = player.picture();
+    let focus: HashSet<Point> = player.focus().iter().copied().collect();
+
+    let clear = terminal::Clear(terminal::ClearType::UntilNewLine);
+    for row in 0..rows.saturating_sub(2) {
+        queue!(out, cursor::MoveTo(0, row as u16))?;
+        let i = top + row;
+        if i >= picture.rows() {
+            queue!(out, clear)?;
+            continue;
+        }
+
+        for j in left..picture.columns().min(left + columns) {
+            let point = Point::new(i, j);
+            let cell = picture[point];
+            if focus.contains(&point) {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            if cell.style != Style::Plain {
+                let Rgb(r, g, b) = colour(cell.style);
+                queue!(out, SetForegroundColor(Color::Rgb { r, g, b }))?;
+            }
+            queue!(
+                out,
+                Print(cell.ch),
+                ResetColor,
+                SetAttribute(Attribute::Reset)
+            )?;
+        }
+        queue!(out, clear)?;
+    }
+
+    let status_row = rows.saturating_sub(2) as u16;
+    let line = |text: &str| text.chars().take(columns).collect::<String>();
+    queue!(
+        out,
+        cursor::MoveTo(0, status_row),
+        SetAttribute(Attribute::Bold),
+        Print(line(status)),
+        SetAttribute(Attribute::Reset),
+        clear,
+        cursor::MoveTo(0, status_row + 1),
+        Print(line(HELP)),
+        clear,
+    )?;
+
+    out.flush()
+}
+
+/// Plays the recordings in the terminal until `q` (or `Esc`) is pressed.
+pub fn view(recordings: &[Recording]) -> io::Result<()> {
+    if recordings.is_empty() {
+        return Err(io::Error::other("nothing was recorded"));
+    }
+
+    let _screen = Screen::enter()?;
+    let mut out = io::stdout();
+
+    let mut current = 0;
+    let mut player = Player::new(&recordings[current]);
+    let (mut playing, mut speed) = (false, 0);
+    let mut offset = (0, 0);
+
+    loop {
+        let (columns, rows) = terminal::size()?;
+        let picture = player.picture();
+        if let Some(&focus) = player.focus().first() {
+            let height = (rows as usize).saturating_sub(2);
+            offset = (
+                follow(offset.0, focus.i, height, picture.rows()),
+                follow(offset.1, focus.j, columns as usize, picture.columns()),
+            );
+        }
+
+        let recording = player.recording();
+        let status = format!(
+            "{} ({}/{})  step {}/{}{}  {}  {}",
+            recording.name,
+            current + 1,
+            recordings.len(),
+            player.position(),
+            recording.steps.len(),
+            if recording.truncated {
+                " (truncated)"
+            } else {
+                ""
+            },
+            if playing {
+                format!("playing \u{00d7}{}", SPEEDS[speed])
+            } else {
+                "paused".to_owned()
+            },
+            player.label(),
+        );
+        draw(&mut out, &player, offset, &status)?;
+
+        if !event::poll(TICK)? {
+            if playing {
+                for _ in 0..SPEEDS[speed] {
+                    if !player.forward() {
+                        playing = false;
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+
+        let Event::Key(KeyEvent { code, kind, .. }) = event::read()? else {
+            continue;
+        };
+        if kind == KeyEventKind::Release {
+            continue;
+        }
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char(' ') => playing = !playing && !player.is_at_end(),
+            KeyCode::Right | KeyCode::Char('l') => {
+                playing = false;
+                player.forward();
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                playing = false;
+                player.backward();
+            }
+            KeyCode::PageDown => player.seek(player.position() + JUMP),
+            KeyCode::PageUp => player.seek(player.position().saturating_sub(JUMP)),
+            KeyCode::Home => player.seek(0),
+            KeyCode::End => player.seek(usize::MAX),
+            KeyCode::Char('+') | KeyCode::Char('=') => speed = (speed + 1).min(SPEEDS.len() - 1),
+            KeyCode::Char('-') => speed = speed.saturating_sub(1),
+            KeyCode::Tab => {
+                current = (current + 1) % recordings.len();
+                player = Player::new(&recordings[current]);
+                (playing, offset) = (false, (0, 0));
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Re-solving a day whenever its sources or its input change, to see how
+//! the answers and the timings moved since the run before.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::inputs;
+use crate::report::{Record, Report};
+use crate::scaffold::workspace_root;
+
+/// How often the files are checked for changes
+const POLL: Duration = Duration::from_millis(300);
+
+/// Modification times of all the files under some paths.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+fn collect(path: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    // files that aren't there (yet) simply aren't in the snapshot
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            collect(&entry.path(), files);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        files.insert(path.to_owned(), modified);
+    }
+}
+
+impl Snapshot {
+    /// Walks the directories among `paths` recursively.
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mut files = BTreeMap::new();
+        for path in paths {
+            collect(path, &mut files);
+        }
+
+        Self(files)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Files that were added, removed or modified since `earlier`, sorted.
+    pub fn changes(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = self
+            .0
+            .iter()
+            .filter(|&(path, modified)| earlier.0.get(path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .chain(
+                earlier
+                    .0
+                    .keys()
+                    .filter(|path| !self.0.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changes.sort();
+
+        changes
+    }
+}
+
+/// Blocks until some of the files change, and then until they've stayed
+/// the same for a moment, as editors and `git` often write in a few goes.
+/// Returns the files that changed since `snapshot`, which is brought up to
+/// date.
+pub fn wait(snapshot: &mut Snapshot, paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut current = Snapshot::take(paths);
+    while current == *snapshot {
+        thread::sleep(POLL);
+        current = Snapshot::take(paths);
+    }
+
+    loop {
+        thread::sleep(POLL);
+        let settled = Snapshot::take(paths);
+        if settled == current {
+            break;
+        }
+        current = settled;
+    }
+
+    let changes = current.changes(snapshot);
+    *snapshot = current;
+
+    changes
+}
+
+/// What solving a day depends on: its crate, the shared crate and `input`s.
+pub fn watched_paths(day: u8, input: &[PathBuf]) -> Vec<PathBuf> {
+    let dir = inputs::day_dir(day);
+    let mut paths = vec![
+        dir.join("Cargo.toml"),
+        dir.join("src"),
+        workspace_root().join("common").join("src"),
+    ];
+    paths.extend_from_slice(input);
+
+    paths
+}
+
+/// Builds the runner anew, as the day may have changed since this one was
+/// built, and solves the day with it. `args` are passed on to `aoc run`.
+///
+/// `None` if there's no report, e.g. when the day doesn't compile: what
+/// went wrong is already on stderr by then.
+pub fn solve(day: u8, args: &[OsString], release: bool) -> io::Result<Option<Report>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .arg("run")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(workspace_root().join("Cargo.toml"))
+        .args(["--package", "aoc"]);
+    if release {
+        command.arg("--release");
+    }
+    command
+        .args(["--", "run", "--format", "json", "--day", &day.to_string()])
+        .args(args);
+
+    let output = command.stderr(Stdio::inherit()).output()?;
+    if output.stdout.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(&output.stdout)
+        .map(Some)
+        .map_err(io::Error::other)
+}
+
+/// A record of the latest run next to the same part's record of the run
+/// before, if there was one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Change<'a> {
+    pub before: Option<&'a Record>,
+    pub after: &'a Record,
+}
+
+pub fn compare<'a>(before: Option<&'a Report>, after: &'a Report) -> Vec<Change<'a>> {
+    after
+        .records
+        .iter()
+        .map(|record| Change {
+            before: before.and_then(|before| {
+                before
+                    .records
+                    .iter()
+                    .find(|other| (other.day, other.part) == (record.day, record.part))
+            }),
+            after: record,
+        })
+        .collect()
+}
+
+fn outcome(record: &Record) -> String {
+    match (&record.answer, &record.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(err)) => format!("error: {err}"),
+        (None, None) => "no answer".to_owned(),
+    }
+}
+
+/// How long the record took, parsing included
+fn millis(record: &Record) -> f64 {
+    record.parse_ms + record.solve_ms
+}
+
+impl Change<'_> {
+    /// Whether the answer (or the error) is still the same, `false` for the
+    /// first run
+    pub fn is_same(&self) -> bool {
+        self.before
+            .is_some_and(|before| outcome(before) == outcome(self.after))
+    }
+}
+
+impl Display for Change<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let after = self.after;
+        match after.part {
+            Some(part) => write!(f, "Day {:02}, part {part}: ", after.day)?,
+            None => write!(f, "Day {:02}: ", after.day)?,
+        }
+
+        write!(f, "{}", outcome(after))?;
+        match self.before {
+            Some(_) if self.is_same() => write!(f, " (same)")?,
+            Some(before) => write!(f, " (was {})", outcome(before))?,
+            None => {}
+        }
+
+        write!(f, ", {:.3} ms", millis(after))?;
+        if let Some(before) = self.before {
+            let delta = millis(after) - millis(before);
+            write!(f, " ({delta:+.3} ms")?;
+            if millis(before) > 0.0 {
+                write!(f, ", {:+.0}%", delta / millis(before) * 100.0)?;
+            }
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
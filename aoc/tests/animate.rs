@@ -0,0 +1,51 @@
+use aoc::find_animator;
+use aoc::viewer::Player;
+use aoc_common::Style;
+
+#[test]
+fn stepping_back_undoes_the_steps() {
+    let input = include_str!("../../day10/test4.txt");
+    let recordings = find_animator(10).unwrap().animate(input).unwrap();
+    assert_eq!(recordings.len(), 2);
+
+    for recording in &recordings {
+        let mut player = Player::new(recording);
+        player.seek(usize::MAX);
+        assert!(player.is_at_end());
+        assert_ne!(player.picture(), &recording.start);
+
+        while player.backward() {}
+        assert_eq!(player.picture(), &recording.start);
+    }
+}
+
+#[test]
+fn pipe_loop_is_followed_all_the_way() {
+    let input = include_str!("../../day10/test4.txt");
+    let recordings = find_animator(10).unwrap().animate(input).unwrap();
+
+    let mut player = Player::new(&recordings[0]);
+    player.seek(usize::MAX);
+    let on_loop = player
+        .picture()
+        .cells()
+        .filter(|(_, cell)| cell.style == Style::Loop)
+        .count();
+    // part 1 is half of the loop
+    assert_eq!(on_loop, 2 * 70);
+}
+
+#[test]
+fn every_pattern_is_recorded() {
+    let input = include_str!("../../day13/test.txt");
+    let recordings = find_animator(13).unwrap().animate(input).unwrap();
+
+    let names: Vec<_> = recordings
+        .iter()
+        .map(|recording| recording.name.as_str())
+        .collect();
+    assert_eq!(names, ["pattern 1", "pattern 2"]);
+    assert!(recordings
+        .iter()
+        .all(|recording| !recording.steps.is_empty()));
+}
@@ -0,0 +1,35 @@
+use std::fs;
+
+use aoc::{answers, Answers};
+use aoc_common::Part;
+
+#[test]
+fn input_hash_is_fnv1a() {
+    assert_eq!(answers::input_hash(""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(answers::input_hash("a"), 0xaf63_dc4c_8601_ec8c);
+}
+
+#[test]
+fn answers_survive_saving_and_loading() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+    let hash = answers::input_hash("input");
+
+    let mut answers = Answers::default();
+    answers.record(3, Part::One, hash, "4361");
+    answers.record(3, Part::Two, hash, "467835");
+    answers.record(3, Part::One, hash, "4362");
+    answers.save(&path).unwrap();
+
+    let loaded = Answers::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, answers);
+    assert_eq!(loaded.get(3, Part::One, hash), Some("4362"));
+    assert_eq!(loaded.get(3, Part::One, hash + 1), None);
+}
+
+#[test]
+fn missing_answers_file_is_empty() {
+    let path = std::env::temp_dir().join("aoc-answers-that-do-not-exist.txt");
+    assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+}
@@ -0,0 +1,34 @@
+use aoc::{cross_check, find_checker};
+
+#[test]
+fn examples_agree() {
+    let checker = find_checker(12).unwrap();
+    let (comparisons, disagreements) =
+        cross_check::check(checker, include_str!("../../day12/test.txt")).unwrap();
+
+    assert!(comparisons.iter().all(|comparison| comparison.agrees()));
+    assert!(disagreements.is_empty());
+}
+
+#[test]
+fn brute_force_handles_rows_without_question_marks() {
+    let checker = find_checker(12).unwrap();
+    let comparisons = checker.check("#.# 1,1\n?.# 1,1\n").unwrap();
+
+    assert_eq!(comparisons[0].naive.as_deref(), Some("2"));
+    assert!(comparisons[0].agrees());
+}
+
+#[test]
+fn disagreement_is_minimised() {
+    // the second start doesn't loop back in as many steps as it took to get
+    // to its end, which the LCM relies on; `XXX` has nothing to do with it
+    let input = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11Z, 11Z)\nXXX = (XXX, XXX)\n\
+                 22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22Z, 22Z)\n";
+    let (_, disagreements) = cross_check::check(find_checker(8).unwrap(), input).unwrap();
+
+    assert_eq!(disagreements.len(), 1);
+    assert_eq!(disagreements[0].comparison.naive.as_deref(), Some("3"));
+    assert_eq!(disagreements[0].comparison.fast.as_deref(), Some("6"));
+    assert!(!disagreements[0].input.contains("XXX"));
+}
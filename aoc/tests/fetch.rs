@@ -0,0 +1,81 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+
+use aoc::Fetcher;
+
+/// Answers a single request with `status` and `body`, handing back the
+/// request line and the headers.
+fn serve_once(status: &str, body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let status = status.to_owned();
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request: Vec<String> = BufReader::new(&stream)
+            .lines()
+            .map(Result::unwrap)
+            .take_while(|line| !line.is_empty())
+            .collect();
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        request
+    });
+
+    (url, server)
+}
+
+fn cache(name: &str) -> PathBuf {
+    let cache = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&cache);
+    cache
+}
+
+#[test]
+fn downloads_once_into_the_cache() {
+    let (url, server) = serve_once("200 OK", "1abc2\npqr3stu8vwx\n");
+    let cache = cache("once");
+    let fetcher = Fetcher::new(&url, &cache).session("secret\n");
+
+    assert_eq!(fetcher.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    let request = server.join().unwrap();
+    assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.1");
+    assert!(request
+        .iter()
+        .any(|header| header == "Cookie: session=secret"));
+    assert_eq!(
+        fs::read_to_string(cache.join("2023/day01.txt")).unwrap(),
+        "1abc2\npqr3stu8vwx\n"
+    );
+
+    // the server is gone, so this one can only come from the cache
+    assert_eq!(fetcher.input(1).unwrap(), "1abc2\npqr3stu8vwx\n");
+
+    fs::remove_dir_all(&cache).unwrap();
+}
+
+#[test]
+fn failed_downloads_are_not_cached() {
+    let (url, server) = serve_once("404 Not Found", "Not found");
+    let cache = cache("locked");
+    let fetcher = Fetcher::new(url, &cache).session("secret").year(2015);
+
+    assert!(fetcher.input(25).is_err());
+    assert_eq!(server.join().unwrap()[0], "GET /2015/day/25/input HTTP/1.1");
+    assert!(!fetcher.cache_path(25).exists());
+}
+
+#[test]
+fn no_session_no_download() {
+    let fetcher = Fetcher::new("http://127.0.0.1:1", cache("anonymous"));
+    let err = fetcher.input(3).unwrap_err();
+
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+}
@@ -0,0 +1,58 @@
+use aoc::{cross_check, find_checker, find_day, GENERATORS};
+use aoc_common::{Part, Size};
+
+const SEEDS: u64 = 20;
+
+#[test]
+fn generated_inputs_are_solvable() {
+    let sizes = [
+        Size {
+            count: 1,
+            length: 1,
+        },
+        Size {
+            count: 3,
+            length: 10,
+        },
+    ];
+
+    for generator in &GENERATORS {
+        let day = find_day(generator.day).unwrap();
+        for seed in 0..SEEDS {
+            for size in sizes {
+                let input = generator.generate(seed, size);
+                for part in Part::ALL {
+                    let answer = day.solve(&input, part);
+                    assert!(
+                        answer.is_ok(),
+                        "day {}, part {part}, seed {seed}, {size:?}: {}\n{input}",
+                        generator.day,
+                        answer.unwrap_err()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for generator in &GENERATORS {
+        let input = generator.generate(7, Size::default());
+        assert_eq!(input, generator.generate(7, Size::default()));
+        assert_ne!(input, generator.generate(8, Size::default()));
+    }
+}
+
+#[test]
+fn generated_inputs_cross_check() {
+    for day in [8, 10, 12] {
+        let generator = GENERATORS.iter().find(|entry| entry.day == day).unwrap();
+        let checker = find_checker(day).unwrap();
+        for seed in 0..SEEDS {
+            let input = generator.generate(seed, Size::default());
+            let (_, disagreements) = cross_check::check(checker, &input).unwrap();
+            assert_eq!(disagreements, [], "day {day}, seed {seed}");
+        }
+    }
+}
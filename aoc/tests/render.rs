@@ -0,0 +1,54 @@
+use std::process::Command;
+
+use aoc::scaffold::workspace_root;
+use aoc::{find_renderer, render, Report};
+use aoc_common::Style;
+
+#[test]
+fn pictures_keep_the_tiles() {
+    let input = include_str!("../../day11/test.txt");
+    let picture = find_renderer(11).unwrap().render(input).unwrap();
+
+    let tiles: Vec<String> = picture
+        .iter_rows()
+        .map(|row| row.iter().map(|cell| cell.ch).collect())
+        .collect();
+    assert_eq!(tiles, input.lines().map(str::trim).collect::<Vec<_>>());
+}
+
+#[test]
+fn day10_marks_the_inside() {
+    let input = include_str!("../../day10/test4.txt");
+    let picture = find_renderer(10).unwrap().render(input).unwrap();
+
+    let inside = picture
+        .cells()
+        .filter(|(_, cell)| cell.style == Style::Inside)
+        .count();
+    assert_eq!(inside, 8);
+}
+
+#[test]
+fn formats() {
+    let input = include_str!("../../day13/test.txt");
+    let picture = find_renderer(13).unwrap().render(input).unwrap();
+
+    assert!(render::png(&picture).unwrap().starts_with(b"\x89PNG"));
+    assert!(render::svg(&picture).trim_end().ends_with("</svg>"));
+    assert!(render::ansi(&picture).contains("\x1b[38;2;"));
+}
+
+#[test]
+fn terminal_pictures_stay_out_of_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "11", "--format", "json", "--render", "-"])
+        .arg("--input")
+        .arg(workspace_root().join("day11/test.txt"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: Report = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report.records.len(), 2);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("\x1b[38;2;"));
+}
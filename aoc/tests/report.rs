@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use aoc::{find_day, report};
+use aoc_common::{Day, Error, Part, Solution};
+
+struct Panics;
+
+impl Solution for Panics {
+    const DAY: u8 = 99;
+
+    type Input = ();
+
+    fn parse(_: &str) -> Result<Self::Input, Error> {
+        Ok(())
+    }
+
+    fn part1(_: &Self::Input) -> Result<impl Display, Error> {
+        Ok(1)
+    }
+
+    fn part2(_: &Self::Input) -> Result<impl Display, Error> {
+        panic!("part 2 is broken");
+        #[allow(unreachable_code)]
+        Ok(2)
+    }
+}
+
+#[test]
+fn records_have_answers_and_timings() {
+    let day = find_day(9).unwrap();
+    let records = report::solve(day, "0 3 6 9 12 15\n", &Part::ALL);
+
+    let answers: Vec<_> = records.iter().map(|r| r.answer.as_deref()).collect();
+    assert_eq!(answers, [Some("18"), Some("-3")]);
+    assert!(records.iter().all(|r| r.is_ok() && r.parse_ms >= 0.0));
+
+    let json = serde_json::to_value(&records[0]).unwrap();
+    for key in ["day", "part", "answer", "parse_ms", "solve_ms"] {
+        assert!(json.get(key).is_some(), "missing `{key}`");
+    }
+}
+
+#[test]
+fn invalid_input_is_a_single_record() {
+    let day = find_day(9).unwrap();
+    let records = report::solve(day, "0 3 x\n", &Part::ALL);
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].part, None);
+    assert!(!records[0].is_ok());
+}
+
+#[test]
+fn panics_are_isolated_to_the_part() {
+    let records = report::solve_isolated(&Day::of::<Panics>(), "", &Part::ALL);
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].answer.as_deref(), Some("1"));
+    assert!(records[0].is_ok());
+    assert_eq!((records[1].day, records[1].part), (99, Some(2)));
+    assert_eq!(records[1].answer, None);
+    assert_eq!(
+        records[1].error.as_deref(),
+        Some("panicked: part 2 is broken")
+    );
+}
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use aoc::scaffold::{new_day, workspace_root};
+use aoc::DAYS;
+
+fn copy(from: &Path, to: &Path, file: &str) {
+    fs::create_dir_all(to.join(file).parent().unwrap()).unwrap();
+    fs::copy(from.join(file), to.join(file)).unwrap();
+}
+
+/// Copies everything under `dir`, relative to the workspace root
+fn copy_dir(to: &Path, dir: &str) {
+    for entry in fs::read_dir(workspace_root().join(dir)).unwrap() {
+        let entry = entry.unwrap();
+        let path = format!("{dir}/{}", entry.file_name().to_str().unwrap());
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(to, &path);
+        } else {
+            copy(&workspace_root(), to, &path);
+        }
+    }
+}
+
+/// A copy of the files `new_day` changes, in a fresh directory
+fn workspace(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"] {
+        copy(&workspace_root(), &root, file);
+    }
+
+    root
+}
+
+#[test]
+fn new_days_get_registered_in_order() {
+    let root = workspace("scaffold");
+
+    new_day(&root, 25).unwrap();
+    new_day(&root, 24).unwrap();
+    assert!(new_day(&root, 24).is_err());
+
+    let lib = fs::read_to_string(root.join("day24/src/lib.rs")).unwrap();
+    assert!(lib.contains("pub struct Day24;") && lib.contains("const DAY: u8 = 24;"));
+    assert!(root.join("day25/test.txt").exists());
+
+    let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace.contains("    \"day24\",\n    \"day25\"\n]"));
+    let runner = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+    assert!(runner.contains(
+        "day24 = { path = \"../day24\" }\n\
+         day25 = { path = \"../day25\" }\n\n[dev-dependencies]"
+    ));
+    let registry = fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+    assert!(registry.contains("    Day::of::<day24::Day24>(),\n    Day::of::<day25::Day25>(),\n];"));
+    assert!(registry.contains(&format!("pub const DAYS: [Day; {}]", DAYS.len() + 2)));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn failing_to_write_changes_nothing() {
+    let root = workspace("scaffold-failing");
+    let registry = root.join("aoc/src/registry.rs");
+    // can't be written to, being a directory
+    fs::create_dir(root.join("aoc/src/registry.rs.part")).unwrap();
+    let before = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+
+    assert!(new_day(&root, 25).is_err());
+    assert!(!root.join("day25").exists());
+    assert!(!root.join("Cargo.toml.part").exists());
+    assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), before);
+    assert!(!fs::read_to_string(registry).unwrap().contains("day25"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn new_day_compiles() {
+    // a workspace of the common crate alone, plus the runner's files for
+    // `new_day` to update
+    let root = workspace("scaffold-check");
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nresolver = \"2\"\n\nmembers = [\n    \"common\"\n]\n",
+    )
+    .unwrap();
+    copy_dir(&root, "common");
+    if workspace_root().join("Cargo.lock").exists() {
+        copy(&workspace_root(), &root, "Cargo.lock");
+    }
+
+    new_day(&root, 25).unwrap();
+    let status = Command::new(env!("CARGO"))
+        .args(["check", "--quiet", "--offline", "--tests"])
+        .args(["--package", "day25", "--manifest-path"])
+        .arg(root.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", root.join("target"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    fs::remove_dir_all(&root).unwrap();
+}
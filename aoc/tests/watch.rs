@@ -0,0 +1,84 @@
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use aoc::watch::compare;
+use aoc::{Record, Report, Snapshot};
+
+fn record(part: u8, answer: &str, solve_ms: f64) -> Record {
+    Record {
+        day: 13,
+        part: Some(part),
+        answer: Some(answer.to_owned()),
+        error: None,
+        parse_ms: 0.0,
+        solve_ms,
+    }
+}
+
+#[test]
+fn changes_since_the_run_before() {
+    let before = Report::new(vec![record(1, "405", 2.0)], Duration::ZERO);
+    let after = Report::new(
+        vec![record(1, "405", 1.0), record(2, "400", 3.0)],
+        Duration::ZERO,
+    );
+
+    let changes: Vec<String> = compare(Some(&before), &after)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            "Day 13, part 1: 405 (same), 1.000 ms (-1.000 ms, -50%)",
+            "Day 13, part 2: 400, 3.000 ms",
+        ]
+    );
+
+    let broken = Report::new(
+        vec![Record {
+            answer: None,
+            error: Some("no solution: no reflection".to_owned()),
+            ..record(1, "", 1.0)
+        }],
+        Duration::ZERO,
+    );
+    let change = compare(Some(&after), &broken)[0];
+    assert!(!change.is_same());
+    assert_eq!(
+        change.to_string(),
+        "Day 13, part 1: error: no solution: no reflection (was 405), 1.000 ms (+0.000 ms, +0%)"
+    );
+}
+
+#[test]
+fn snapshots_see_modified_added_and_removed_files() {
+    let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let (lib, input) = (dir.join("src/lib.rs"), dir.join("input.txt"));
+    fs::write(&lib, "").unwrap();
+    let paths = [dir.join("src"), input.clone()];
+
+    let before = Snapshot::take(&paths);
+    assert_eq!(before.len(), 1);
+    assert!(Snapshot::take(&paths).changes(&before).is_empty());
+
+    let later = SystemTime::now() + Duration::from_secs(10);
+    fs::File::options()
+        .write(true)
+        .open(&lib)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    fs::write(&input, "").unwrap();
+    assert_eq!(
+        Snapshot::take(&paths).changes(&before),
+        [input.clone(), lib]
+    );
+
+    fs::remove_dir_all(dir.join("src")).unwrap();
+    let after = Snapshot::take(&paths);
+    assert_eq!(after.changes(&before), [input, dir.join("src/lib.rs")]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,137 @@
+use std::fmt;
+
+use crate::error::Error;
+use crate::point::Point;
+use crate::render::{Cell, Picture};
+use crate::solution::Solution;
+
+/// Steps after which a recording stops, so that long traversals don't eat
+/// up all the memory
+pub const MAX_STEPS: usize = 100_000;
+
+/// A single event of an algorithm: the tiles it changed and the ones it's
+/// looking at right now.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Step {
+    pub label: String,
+    pub changes: Vec<(Point, Cell)>,
+    pub focus: Vec<Point>,
+}
+
+impl Step {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn change(mut self, point: Point, cell: Cell) -> Self {
+        self.changes.push((point, cell));
+        self
+    }
+
+    pub fn focus(mut self, points: impl IntoIterator<Item = Point>) -> Self {
+        self.focus.extend(points);
+        self
+    }
+}
+
+/// An algorithm run, step by step, over a picture of its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recording {
+    pub name: String,
+    pub start: Picture,
+    pub steps: Vec<Step>,
+    /// Whether the algorithm went on for more than [`MAX_STEPS`]
+    pub truncated: bool,
+}
+
+/// Collects [`Recording`]s from within the algorithms. Algorithms take one
+/// along, and solving hands them [`Recorder::off`], which records nothing
+/// (nor even builds the steps).
+#[derive(Debug, Default)]
+pub struct Recorder {
+    recordings: Option<Vec<Recording>>,
+}
+
+impl Recorder {
+    pub fn off() -> Self {
+        Self { recordings: None }
+    }
+
+    pub fn on() -> Self {
+        Self {
+            recordings: Some(vec![]),
+        }
+    }
+
+    /// Whether the steps go anywhere, e.g. to skip preparing for them
+    pub fn is_on(&self) -> bool {
+        self.recordings.is_some()
+    }
+
+    /// Starts recording another algorithm, which begins with `picture`.
+    pub fn start(&mut self, name: impl Into<String>, picture: impl FnOnce() -> Picture) {
+        if let Some(recordings) = &mut self.recordings {
+            recordings.push(Recording {
+                name: name.into(),
+                start: picture(),
+                steps: vec![],
+                truncated: false,
+            });
+        }
+    }
+
+    /// Adds a step to the latest recording.
+    pub fn step(&mut self, step: impl FnOnce() -> Step) {
+        let Some(recording) = self.recordings.as_mut().and_then(|all| all.last_mut()) else {
+            return;
+        };
+        if recording.steps.len() == MAX_STEPS {
+            recording.truncated = true;
+            return;
+        }
+
+        recording.steps.push(step());
+    }
+
+    pub fn finish(self) -> Vec<Recording> {
+        self.recordings.unwrap_or_default()
+    }
+}
+
+/// A day that can record its algorithms, to be stepped through.
+pub trait Animate: Solution {
+    fn animate(input: &Self::Input) -> Result<Vec<Recording>, Error>;
+}
+
+/// A type-erased [`Animate`], like [`crate::Day`] is for solutions.
+#[derive(Clone, Copy)]
+pub struct Animator {
+    pub day: u8,
+    animate: fn(&str) -> Result<Vec<Recording>, Error>,
+}
+
+fn animate<S: Animate>(input: &str) -> Result<Vec<Recording>, Error> {
+    S::animate(&S::parse(input)?)
+}
+
+impl Animator {
+    pub const fn of<S: Animate>() -> Self {
+        Self {
+            day: S::DAY,
+            animate: animate::<S>,
+        }
+    }
+
+    pub fn animate(&self, input: &str) -> Result<Vec<Recording>, Error> {
+        (self.animate)(input)
+    }
+}
+
+impl fmt::Debug for Animator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Animator").field("day", &self.day).finish()
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::{self, Display};
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+/// Answers of a naive and an optimised algorithm on the same input.
+///
+/// A missing answer means the algorithm gave up (e.g. the brute force
+/// would take too long on that input), so there is nothing to compare.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub name: &'static str,
+    pub naive: Option<String>,
+    pub fast: Option<String>,
+}
+
+impl Comparison {
+    pub fn new(
+        name: &'static str,
+        naive: Option<impl Display>,
+        fast: Option<impl Display>,
+    ) -> Self {
+        Self {
+            name,
+            naive: naive.map(|answer| answer.to_string()),
+            fast: fast.map(|answer| answer.to_string()),
+        }
+    }
+
+    pub fn agrees(&self) -> bool {
+        match (&self.naive, &self.fast) {
+            (Some(naive), Some(fast)) => naive == fast,
+            _ => true,
+        }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answer = |answer: &Option<String>| answer.clone().unwrap_or_else(|| "-".to_owned());
+        write!(
+            f,
+            "{}: naive {}, fast {}",
+            self.name,
+            answer(&self.naive),
+            answer(&self.fast)
+        )
+    }
+}
+
+/// A day that keeps both a naive and an optimised version of (some of)
+/// its algorithms around.
+pub trait CrossCheck: Solution {
+    /// Runs every pair of alternative algorithms on `input`.
+    fn cross_check(input: &Self::Input) -> Vec<Comparison>;
+}
+
+/// A type-erased [`CrossCheck`], like [`crate::Day`] is for solutions.
+#[derive(Clone, Copy)]
+pub struct Checker {
+    pub day: u8,
+    check: fn(&str) -> Result<Vec<Comparison>, Error>,
+}
+
+fn check<S: CrossCheck>(input: &str) -> Result<Vec<Comparison>, Error> {
+    Ok(S::cross_check(&S::parse(input)?))
+}
+
+impl Checker {
+    pub const fn of<S: CrossCheck>() -> Self {
+        Self {
+            day: S::DAY,
+            check: check::<S>,
+        }
+    }
+
+    pub fn check(&self, input: &str) -> Result<Vec<Comparison>, Error> {
+        (self.check)(input)
+    }
+}
+
+impl fmt::Debug for Checker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checker").field("day", &self.day).finish()
+    }
+}
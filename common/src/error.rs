@@ -0,0 +1,113 @@
+use std::fmt;
+
+use crate::grid::GridError;
+
+/// Where and why the input didn't have the expected shape.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending token.
+    pub line: usize,
+    /// 1-based column (in characters) of the offending token.
+    pub column: usize,
+    /// The offending token, empty if something is missing.
+    pub token: String,
+    /// What should have been there instead, e.g. "a number".
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, expected: &str) -> Self {
+        Self {
+            line,
+            column,
+            token: token.to_owned(),
+            expected: expected.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.token.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Same as the `From` conversion, but says which tiles are allowed.
+    pub fn from_grid(err: GridError, tile: &str) -> Self {
+        match err {
+            GridError::UnexpectedChar { line, column, ch } => ParseError {
+                line,
+                column,
+                token: ch.to_string(),
+                expected: tile.to_owned(),
+            },
+            other => other.into(),
+        }
+    }
+}
+
+impl From<GridError> for ParseError {
+    fn from(err: GridError) -> Self {
+        match err {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => ParseError {
+                line,
+                column: expected.min(found) + 1,
+                token: String::new(),
+                expected: format!("a row of {expected} tiles (found {found})"),
+            },
+            GridError::UnexpectedChar { line, column, ch } => ParseError {
+                line,
+                column,
+                token: ch.to_string(),
+                expected: "a valid tile".to_owned(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input doesn't have the shape the puzzle describes.
+    Parse(ParseError),
+    /// The input is well-formed, but the puzzle has no answer for it.
+    NoSolution(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "invalid input: {err}"),
+            Error::NoSolution(reason) => write!(f, "no solution: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<GridError> for Error {
+    fn from(err: GridError) -> Self {
+        Error::Parse(err.into())
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::solution::Solution;
+
+/// Generator of the random inputs. Unlike `rand`'s `StdRng` it's
+/// guaranteed to give the same numbers everywhere, so a seed always
+/// reproduces the same input.
+pub type GenRng = ChaCha8Rng;
+
+/// How big a generated input should be.
+///
+/// Every day reads it in its own way (see its [`Generate`] impl), but
+/// `count` is roughly the number of lines (or games, patterns, ...) and
+/// `length` how long each of them is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Size {
+    pub count: usize,
+    pub length: usize,
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        Self {
+            count: 10,
+            length: 10,
+        }
+    }
+}
+
+/// A day that can make up its own, well-formed inputs.
+pub trait Generate: Solution {
+    /// An input [`Solution::parse`] accepts, random but fully decided by `rng`.
+    fn generate(rng: &mut GenRng, size: Size) -> String;
+}
+
+/// A type-erased [`Generate`], like [`crate::Day`] is for solutions.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub day: u8,
+    generate: fn(&mut GenRng, Size) -> String,
+}
+
+impl Generator {
+    pub const fn of<S: Generate>() -> Self {
+        Self {
+            day: S::DAY,
+            generate: S::generate,
+        }
+    }
+
+    pub fn generate(&self, seed: u64, size: Size) -> String {
+        (self.generate)(&mut GenRng::seed_from_u64(seed), size)
+    }
+}
+
+impl fmt::Debug for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator").field("day", &self.day).finish()
+    }
+}
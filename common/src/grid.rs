@@ -0,0 +1,323 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::input::{self, Line};
+use crate::point::{Direction, Point};
+
+/// Offsets of all 8 neighbours of a tile, row by row.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular, row-major 2D grid.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A row (1-based `line`) has a different length than the first one.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The char -> tile mapping rejected a character (1-based position).
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        ch: char,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line}: expected a row of {expected} tiles, found {found}"
+            ),
+            GridError::UnexpectedChar { line, column, ch } => {
+                write!(f, "line {line}, column {column}: unexpected tile `{ch}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, columns: usize, fill: T) -> Self {
+        Self {
+            cells: vec![fill; rows * columns],
+            rows,
+            columns,
+        }
+    }
+
+    /// Swaps rows with columns.
+    pub fn transposed(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for j in 0..self.columns {
+            cells.extend(self.column(j).cloned());
+        }
+
+        Self {
+            cells,
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+
+    pub fn rotated_clockwise(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for j in 0..self.columns {
+            cells.extend(self.column(j).rev().cloned());
+        }
+
+        Self {
+            cells,
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+
+    pub fn rotated_counterclockwise(&self) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for j in (0..self.columns).rev() {
+            cells.extend(self.column(j).cloned());
+        }
+
+        Self {
+            cells,
+            rows: self.columns,
+            columns: self.rows,
+        }
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.iter_rows().map(|row| row.to_vec()).collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid out of rows, which all have to be of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let columns = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(rows.len() * columns);
+        let row_count = rows.len();
+
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != columns {
+                return Err(GridError::Ragged {
+                    line: i + 1,
+                    expected: columns,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            cells,
+            rows: row_count,
+            columns,
+        })
+    }
+
+    /// Parses a grid out of text, one row per (non-blank) line, mapping
+    /// every character with `tile`. Characters for which `tile` returns
+    /// `None` are reported as [`GridError::UnexpectedChar`].
+    pub fn parse<F>(input: &str, tile: F) -> Result<Self, GridError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        Self::parse_lines(input::lines(input), tile)
+    }
+
+    /// Same as [`Grid::parse`], for lines already split out of the input
+    /// (e.g. a single paragraph), so errors point at the original lines.
+    pub fn parse_lines<'a, I, F>(lines: I, mut tile: F) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut rows = vec![];
+        let mut numbers = vec![];
+
+        for line in lines {
+            if line.is_blank() {
+                continue;
+            }
+
+            let mut row = Vec::with_capacity(line.text.len());
+            for (column, ch) in line.text.chars().enumerate() {
+                match tile(ch) {
+                    Some(t) => row.push(t),
+                    None => {
+                        return Err(GridError::UnexpectedChar {
+                            line: line.number,
+                            column: column + 1,
+                            ch,
+                        })
+                    }
+                }
+            }
+            rows.push(row);
+            numbers.push(line.number);
+        }
+
+        Self::from_rows(rows).map_err(|err| match err {
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => GridError::Ragged {
+                line: numbers[line - 1],
+                expected,
+                found,
+            },
+            other => other,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.i < self.rows && point.j < self.columns
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.i * self.columns + point.j])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.i * self.columns + point.j])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.columns..(i + 1) * self.columns]
+    }
+
+    pub fn column(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> + Clone + '_ {
+        assert!(j < self.columns, "column {j} out of bounds");
+        self.cells[j..].iter().step_by(self.columns)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.rows).map(|i| self.row(i))
+    }
+
+    /// Iterates over all the tiles together with their positions, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(n, cell)| (Point::new(n / self.columns, n % self.columns), cell))
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|n| Point::new(n / self.columns, n % self.columns))
+    }
+
+    /// Moves `point` one tile in `direction`, if it stays inside the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        point.step(direction).filter(|&p| self.contains(p))
+    }
+
+    /// Top, bottom, left and right neighbours that are inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, Direction)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((self.step(point, direction)?, direction)))
+    }
+
+    /// All 8 neighbours (including diagonals) that are inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS8
+            .into_iter()
+            .filter_map(move |(di, dj)| point.offset(di, dj))
+            .filter(|&p| self.contains(p))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {point:?} is outside of the grid"))
+    }
+}
+
+/// Prints the grid row by row, tiles separated with a space.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for (j, cell) in row.iter().enumerate() {
+                if j != 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
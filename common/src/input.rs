@@ -0,0 +1,169 @@
+//! Line-ending agnostic splitting of puzzle inputs.
+//!
+//! Inputs downloaded on Windows come with `\r\n` endings (and sometimes a
+//! BOM), while the example files are plain `\n`, so nothing should depend
+//! on the exact line terminator.
+
+use std::mem;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+const BOM: char = '\u{feff}';
+
+/// A single line of the input, without its terminator and trailing whitespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number in the original input.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn span(&self) -> Span<'a> {
+        Span {
+            line: self.number,
+            column: 1,
+            text: self.text,
+        }
+    }
+}
+
+/// A piece of a line that remembers where it came from, so that parsers
+/// can point at the exact token they didn't like.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    /// 1-based line number in the original input.
+    pub line: usize,
+    /// 1-based column (in characters) of the first character.
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// An empty span at the start of `line`, for reporting lines that
+    /// are missing altogether.
+    pub fn missing(line: usize) -> Self {
+        Self {
+            line,
+            column: 1,
+            text: "",
+        }
+    }
+
+    /// Turns `piece`, which has to be a subslice of `self.text`, into a span.
+    fn sub(self, piece: &'a str) -> Self {
+        let start = piece.as_ptr() as usize - self.text.as_ptr() as usize;
+        Self {
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+            text: piece,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An empty span right after the last character, for reporting
+    /// tokens that are missing.
+    pub fn end(self) -> Self {
+        self.sub(&self.text[self.text.len()..])
+    }
+
+    pub fn trim(self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn strip_prefix(self, prefix: &str) -> Option<Self> {
+        Some(self.sub(self.text.strip_prefix(prefix)?))
+    }
+
+    pub fn strip_suffix(self, suffix: &str) -> Option<Self> {
+        Some(self.sub(self.text.strip_suffix(suffix)?))
+    }
+
+    pub fn split_once(self, delimiter: &str) -> Option<(Self, Self)> {
+        let (left, right) = self.text.split_once(delimiter)?;
+        Some((self.sub(left), self.sub(right)))
+    }
+
+    pub fn split(self, delimiter: char) -> impl Iterator<Item = Self> + 'a {
+        self.text.split(delimiter).map(move |piece| self.sub(piece))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Self> + 'a {
+        self.text
+            .split_whitespace()
+            .map(move |piece| self.sub(piece))
+    }
+
+    /// Every character together with its own single-character span.
+    pub fn chars(self) -> impl Iterator<Item = (Self, char)> + 'a {
+        self.text
+            .char_indices()
+            .enumerate()
+            .map(move |(n, (i, ch))| {
+                let span = Self {
+                    line: self.line,
+                    column: self.column + n,
+                    text: &self.text[i..i + ch.len_utf8()],
+                };
+                (span, ch)
+            })
+    }
+
+    pub fn error(self, expected: &str) -> ParseError {
+        ParseError::new(self.line, self.column, self.text, expected)
+    }
+
+    pub fn parse<T: FromStr>(self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+}
+
+/// Splits the input into lines, handling both `\n` and `\r\n` endings.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .strip_prefix(BOM)
+        .unwrap_or(input)
+        .lines()
+        .enumerate()
+        .map(|(n, text)| Line {
+            number: n + 1,
+            text: text.trim_end(),
+        })
+}
+
+/// Splits the input into paragraphs, i.e. groups of lines separated by one
+/// or more blank lines. Leading and trailing blank lines are ignored.
+pub fn paragraphs(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut paragraphs = vec![];
+    let mut current = vec![];
+
+    for line in lines(input) {
+        if line.is_blank() {
+            if !current.is_empty() {
+                paragraphs.push(mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs
+}
+
+/// The same input as saved by a Windows editor: a BOM, `\r\n` endings,
+/// trailing spaces and blank lines at the end. For testing that days read
+/// such inputs the same.
+pub fn saved_on_windows(input: &str) -> String {
+    format!("{BOM}{}\r\n  \r\n", input.replace('\n', " \r\n"))
+}
@@ -0,0 +1,21 @@
+//! Utilities shared by all the days of Advent of Code 2023.
+
+pub mod animate;
+pub mod cross_check;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod point;
+pub mod render;
+pub mod solution;
+
+pub use animate::{Animate, Animator, Recorder, Recording, Step};
+pub use cross_check::{Checker, Comparison, CrossCheck};
+pub use error::{Error, ParseError};
+pub use generate::{GenRng, Generate, Generator, Size};
+pub use grid::{Grid, GridError};
+pub use input::{lines, paragraphs, Line, Span};
+pub use point::{Direction, Point};
+pub use render::{Cell, Picture, Render, Renderer, Style};
+pub use solution::{Day, Part, Solution, Solve};
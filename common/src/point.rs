@@ -0,0 +1,89 @@
+/// A position on a grid: `i` is the row, `j` is the column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub i: usize,
+    pub j: usize,
+}
+
+impl Point {
+    pub const fn new(i: usize, j: usize) -> Self {
+        Self { i, j }
+    }
+
+    /// Moves the point by `(di, dj)`, or returns `None` if it would
+    /// end up at a negative coordinate.
+    pub fn offset(self, di: isize, dj: isize) -> Option<Self> {
+        Some(Self {
+            i: self.i.checked_add_signed(di)?,
+            j: self.j.checked_add_signed(dj)?,
+        })
+    }
+
+    /// Moves the point by one tile in `direction`.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (di, dj) = direction.offset();
+        self.offset(di, dj)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.i.abs_diff(other.i) + self.j.abs_diff(other.j)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((i, j): (usize, usize)) -> Self {
+        Self { i, j }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Top,
+        Direction::Bottom,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// `(di, dj)` of a single step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        use Direction::*;
+        match self {
+            Top => (-1, 0),
+            Bottom => (1, 0),
+            Left => (0, -1),
+            Right => (0, 1),
+        }
+    }
+
+    pub fn reversed(self) -> Self {
+        use Direction::*;
+        match self {
+            Top => Bottom,
+            Bottom => Top,
+            Left => Right,
+            Right => Left,
+        }
+    }
+
+    pub fn turned_left(self) -> Self {
+        use Direction::*;
+        match self {
+            Top => Left,
+            Left => Bottom,
+            Bottom => Right,
+            Right => Top,
+        }
+    }
+
+    pub fn turned_right(self) -> Self {
+        self.turned_left().reversed()
+    }
+}
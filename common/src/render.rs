@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::error::Error;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+/// What a tile of a picture means, so that all the days share a palette.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Nothing there: the `.`s, the padding between boards
+    Empty,
+    /// A tile that takes no part in the answer
+    Plain,
+    /// A pipe of the loop (day 10)
+    Loop,
+    /// A tile enclosed by the loop (day 10)
+    Inside,
+    /// A number next to a symbol (day 3)
+    PartNumber,
+    /// A symbol that isn't a gear (day 3)
+    Symbol,
+    /// A symbol next to exactly two part numbers (day 3)
+    Gear,
+    /// A galaxy (day 11)
+    Galaxy,
+    /// Space that gets expanded (day 11)
+    Expanded,
+    /// Rows or columns right next to a line of reflection (day 13)
+    Reflection,
+    /// The tile that has to be flipped to find the other line (day 13)
+    Smudge,
+    /// A tile an algorithm is yet to get to (e.g. queued by a flood fill)
+    Frontier,
+    /// A tile an algorithm is done with
+    Visited,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Cell {
+    /// Padding, e.g. between boards of different sizes
+    pub const BLANK: Cell = Cell::new(' ', Style::Empty);
+
+    pub const fn new(ch: char, style: Style) -> Self {
+        Self { ch, style }
+    }
+}
+
+/// A grid of styled tiles, ready to be drawn.
+pub type Picture = Grid<Cell>;
+
+/// A day that can draw its input together with what it found in there.
+pub trait Render: Solution {
+    fn render(input: &Self::Input) -> Result<Picture, Error>;
+}
+
+/// A type-erased [`Render`], like [`crate::Day`] is for solutions.
+#[derive(Clone, Copy)]
+pub struct Renderer {
+    pub day: u8,
+    render: fn(&str) -> Result<Picture, Error>,
+}
+
+fn render<S: Render>(input: &str) -> Result<Picture, Error> {
+    S::render(&S::parse(input)?)
+}
+
+impl Renderer {
+    pub const fn of<S: Render>() -> Self {
+        Self {
+            day: S::DAY,
+            render: render::<S>,
+        }
+    }
+
+    pub fn render(&self, input: &str) -> Result<Picture, Error> {
+        (self.render)(input)
+    }
+}
+
+impl fmt::Debug for Renderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Renderer").field("day", &self.day).finish()
+    }
+}
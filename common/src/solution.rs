@@ -0,0 +1,106 @@
+use std::fmt::{self, Display};
+
+use crate::error::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A solution to a single day of the puzzle.
+///
+/// Parts return a `Result` too, because some inputs parse fine but have
+/// no answer for one of the parts (e.g. a day08 network without `AAA`).
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<impl Display, Error>;
+    fn part2(input: &Self::Input) -> Result<impl Display, Error>;
+}
+
+/// A parsed input of any day, with answers already formatted.
+pub trait Solve {
+    fn solve(&self, part: Part) -> Result<String, Error>;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Solve for Parsed<S> {
+    fn solve(&self, part: Part) -> Result<String, Error> {
+        match part {
+            Part::One => S::part1(&self.0).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&self.0).map(|answer| answer.to_string()),
+        }
+    }
+}
+
+/// A type-erased [`Solution`], so that all the days can be kept together.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(&str) -> Result<Box<dyn Solve>, Error>,
+    type_name: fn() -> &'static str,
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solve>, Error> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+impl Day {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Self {
+            day: S::DAY,
+            parse: parse::<S>,
+            type_name: std::any::type_name::<S>,
+        }
+    }
+
+    /// Log target of the day, i.e. the name of the crate that solves it.
+    pub fn target(&self) -> &'static str {
+        let name = (self.type_name)();
+        name.split("::").next().unwrap_or(name)
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solve>, Error> {
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<String, Error> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("day", &self.day).finish()
+    }
+}
@@ -0,0 +1,84 @@
+use aoc_common::{Grid, GridError, Point};
+
+/// 2 rows of 3 columns:
+/// ```text
+/// 1 2 3
+/// 4 5 6
+/// ```
+fn wide() -> Grid<u8> {
+    Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+}
+
+fn digit(ch: char) -> Option<u32> {
+    ch.to_digit(10)
+}
+
+#[test]
+fn transposing_swaps_rows_with_columns() {
+    let transposed = wide().transposed();
+
+    assert_eq!((transposed.rows(), transposed.columns()), (3, 2));
+    assert_eq!(transposed.to_rows(), [[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(transposed.transposed(), wide());
+}
+
+#[test]
+fn rotating() {
+    let clockwise = wide().rotated_clockwise();
+    assert_eq!((clockwise.rows(), clockwise.columns()), (3, 2));
+    assert_eq!(clockwise.to_rows(), [[4, 1], [5, 2], [6, 3]]);
+
+    let counterclockwise = wide().rotated_counterclockwise();
+    assert_eq!(counterclockwise.to_rows(), [[3, 6], [2, 5], [1, 4]]);
+
+    assert_eq!(clockwise.rotated_counterclockwise(), wide());
+    let around = (0..4).fold(wide(), |grid, _| grid.rotated_clockwise());
+    assert_eq!(around, wide());
+}
+
+#[test]
+fn neighbours_stay_inside() {
+    let grid = Grid::new(3, 4, ());
+    let neighbours = |i, j| {
+        let mut points: Vec<_> = grid
+            .neighbours8(Point::new(i, j))
+            .map(|p| (p.i, p.j))
+            .collect();
+        points.sort_unstable();
+        points
+    };
+
+    assert_eq!(neighbours(0, 0), [(0, 1), (1, 0), (1, 1)]);
+    assert_eq!(neighbours(2, 3), [(1, 2), (1, 3), (2, 2)]);
+    assert_eq!(neighbours(0, 3), [(0, 2), (1, 2), (1, 3)]);
+    assert_eq!(neighbours(0, 1), [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    assert_eq!(neighbours(1, 0), [(0, 0), (0, 1), (1, 1), (2, 0), (2, 1)]);
+    assert_eq!(neighbours(1, 1).len(), 8);
+
+    let single = Grid::new(1, 1, ());
+    assert_eq!(single.neighbours8(Point::new(0, 0)).count(), 0);
+}
+
+#[test]
+fn parsing() {
+    let grid = Grid::parse("\u{feff}123\r\n456\r\n\r\n", digit).unwrap();
+    assert_eq!(grid.to_rows(), [[1, 2, 3], [4, 5, 6]]);
+
+    assert_eq!(
+        Grid::parse("12\n3x\n", digit),
+        Err(GridError::UnexpectedChar {
+            line: 2,
+            column: 2,
+            ch: 'x'
+        })
+    );
+    assert_eq!(
+        Grid::parse("123\n\n45\n", digit),
+        Err(GridError::Ragged {
+            line: 3,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert!(Grid::parse("", digit).unwrap().is_empty());
+}
@@ -0,0 +1,114 @@
+//! Showing what the calibration values were made of: every line with all
+//! its words marked, the first and the last one standing out.
+
+use std::fmt::{self, Display};
+
+use aoc_common::{lines, Line};
+
+use crate::recogniser::{Recogniser, Token};
+
+/// Marks the first and the last word under the line
+const CHOSEN: char = '^';
+/// Marks the other words
+const OTHER: char = '-';
+
+/// A line of the input together with all the words found in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotated<'a> {
+    pub line: Line<'a>,
+    /// In the order they end, see [`Recogniser::tokens`]
+    pub tokens: Vec<Token>,
+    pub first_and_last: Option<(Token, Token)>,
+}
+
+impl Annotated<'_> {
+    /// `None` for a line without any digits or words
+    pub fn value(&self) -> Option<u32> {
+        self.first_and_last
+            .map(|(first, last)| first.value * 10 + last.value)
+    }
+
+    fn column(&self, byte: usize) -> usize {
+        self.line.text[..byte].chars().count()
+    }
+
+    /// `^` under the first and the last word, `-` under the other ones
+    fn markers(&self) -> String {
+        let mut markers = vec![' '; self.line.text.chars().count()];
+        let mut mark = |token: &Token, marker: char| {
+            let columns = self.column(token.start)..self.column(token.end);
+            for slot in &mut markers[columns] {
+                if *slot != CHOSEN {
+                    *slot = marker;
+                }
+            }
+        };
+        if let Some((first, last)) = &self.first_and_last {
+            mark(first, CHOSEN);
+            mark(last, CHOSEN);
+        }
+        for token in &self.tokens {
+            mark(token, OTHER);
+        }
+
+        let markers: String = markers.into_iter().collect();
+        markers.trim_end().to_owned()
+    }
+}
+
+/// Two lines: the line's number, its value and text, and under the text the
+/// markers of the words.
+impl Display for Annotated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.number;
+        match self.value() {
+            Some(value) => {
+                writeln!(f, "{number:>5} {value:>4}  {}", self.line.text)?;
+                write!(f, "{:>5} {:>4}  {}", "", "", self.markers())
+            }
+            None => write!(f, "{number:>5} {:>4}  {}  (no digits)", "-", self.line.text),
+        }
+    }
+}
+
+/// All the lines of the input, annotated.
+pub fn annotate<'a>(
+    input: &'a str,
+    recogniser: &'a Recogniser,
+) -> impl Iterator<Item = Annotated<'a>> + 'a {
+    lines(input).map(|line| Annotated {
+        line,
+        tokens: recogniser.tokens(line.text).collect(),
+        first_and_last: recogniser.first_and_last(line.text),
+    })
+}
+
+/// The total of an annotated input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub sum: u128,
+    pub lines: usize,
+    /// Lines that count as 0, as there's nothing to make a value of
+    pub without_digits: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, line: &Annotated) {
+        self.lines += 1;
+        match line.value() {
+            Some(value) => self.sum += u128::from(value),
+            None => self.without_digits += 1,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sum: {} of {} lines", self.sum, self.lines)?;
+        if self.without_digits > 0 {
+            write!(f, ", {} of them without digits", self.without_digits)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use aoc_common::{lines, Error, GenRng, Generate, Size, Solution};
+use log::trace;
+use rand::Rng;
+
+mod annotate;
+mod numbers;
+mod recogniser;
+mod stream;
+mod vocabulary;
+
+pub use annotate::{annotate, Annotated, Summary};
+pub use numbers::{Combine, Number, Numbers, NUMBER_WORDS};
+pub use recogniser::{Recogniser, Token, Tokens};
+pub use stream::{calibration_sum_parallel, calibration_sum_reader};
+pub use vocabulary::{parse_words, Language, DIGITS, ENGLISH, GERMAN, POLISH, SPANISH};
+
+/// What makes the calibration value of a line: the digits and words of a
+/// [`Recogniser`], or the whole numbers of [`Numbers`].
+pub trait Calibrate: Sync {
+    /// 0 for a line without anything to make a value of, `None` if the value
+    /// doesn't fit in a `u128`
+    fn calibration_value(&self, line: &str) -> Option<u128>;
+}
+
+/// Tens from the first word (or digit) of the line, ones from the last
+impl Calibrate for Recogniser {
+    fn calibration_value(&self, line: &str) -> Option<u128> {
+        let Some((first, last)) = self.first_and_last(line) else {
+            return Some(0);
+        };
+        trace!(
+            "{line:?}, left: {} at {}..{}, right: {} at {}..{}",
+            first.value,
+            first.start,
+            first.end,
+            last.value,
+            last.start,
+            last.end
+        );
+
+        Combine::Tens.apply(first.value.into(), last.value.into())
+    }
+}
+
+/// Sum of the calibration values of all the lines, with the words of any
+/// vocabulary (the puzzle's is [`DIGITS`] and [`ENGLISH`]). See [`annotate`]
+/// for where the values came from. `None` if the sum doesn't fit in a `u128`.
+pub fn calibration_sum<C: Calibrate + ?Sized>(contents: &str, calibrate: &C) -> Option<u128> {
+    lines(contents).try_fold(0u128, |sum, line| {
+        sum.checked_add(calibrate.calibration_value(line.text)?)
+    })
+}
+
+fn too_big() -> Error {
+    Error::NoSolution("the sum doesn't fit in 128 bits".to_owned())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        calibration_sum(input, &Recogniser::new(DIGITS)).ok_or_else(too_big)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        calibration_sum(input, &Recogniser::new(DIGITS.into_iter().chain(ENGLISH)))
+            .ok_or_else(too_big)
+    }
+}
+
+/// `count` lines of about `length` letters, digits and spelled out digits,
+/// every one with at least one real digit
+impl Generate for Day01 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let random_digit = |rng: &mut GenRng| char::from(b'0' + rng.gen_range(1..=9));
+
+        let mut input = String::new();
+        for _ in 0..size.count {
+            let mut line = String::new();
+            while line.len() < size.length {
+                match rng.gen_range(0..10) {
+                    0 | 1 => line.push(random_digit(rng)),
+                    2 => line.push_str(ENGLISH[rng.gen_range(0..ENGLISH.len())].0),
+                    _ => line.push(char::from(rng.gen_range(b'a'..=b'z'))),
+                }
+            }
+            let digit = random_digit(rng);
+            line.insert(rng.gen_range(0..=line.len()), digit);
+
+            input.push_str(&line);
+            input.push('\n');
+        }
+
+        input
+    }
+}
@@ -0,0 +1,298 @@
+//! Calibrating with whole numbers rather than digits: numerals (`1905`) and
+//! English number words, compound ones too (`twenty-three`, `one hundred
+//! and five`), made into a value by a [`Combine`] rule.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use log::trace;
+
+use crate::recogniser::{Recogniser, Token};
+use crate::Calibrate;
+
+/// The words numbers are made of, scales up to a billion
+pub const NUMBER_WORDS: [(&str, u32); 32] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+    ("hundred", 100),
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+];
+
+/// How the first and the last number of a line make its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Combine {
+    /// `first * 10 + last`, like the digits in the puzzle
+    #[default]
+    Tens,
+    /// The digits of `last` written after the ones of `first`: `23` and
+    /// `100` make `23100`
+    Concat,
+}
+
+impl Combine {
+    /// `None` if the value doesn't fit in a `u128`
+    pub fn apply(self, first: u128, last: u128) -> Option<u128> {
+        let shift = match self {
+            Combine::Tens => 10,
+            Combine::Concat => 10u128.checked_pow(last.checked_ilog10().unwrap_or(0) + 1)?,
+        };
+        first.checked_mul(shift)?.checked_add(last)
+    }
+}
+
+impl Display for Combine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Combine::Tens => write!(f, "tens"),
+            Combine::Concat => write!(f, "concat"),
+        }
+    }
+}
+
+impl FromStr for Combine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tens" => Ok(Combine::Tens),
+            "concat" => Ok(Combine::Concat),
+            _ => Err(format!("unknown rule `{s}`, expected tens or concat")),
+        }
+    }
+}
+
+/// A number found in a line, `start..end` being its bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Number {
+    pub value: u128,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Zero,
+    Unit,
+    Teen,
+    Tens,
+    Hundred,
+    Scale,
+}
+
+impl Kind {
+    fn of(value: u32) -> Self {
+        match value {
+            0 => Kind::Zero,
+            1..=9 => Kind::Unit,
+            10..=19 => Kind::Teen,
+            20..=90 => Kind::Tens,
+            100 => Kind::Hundred,
+            _ => Kind::Scale,
+        }
+    }
+}
+
+/// A number being read word by word, e.g. `two thousand one hundred`
+#[derive(Clone, Copy, Debug)]
+struct Compound {
+    /// Complete groups, e.g. the two thousand
+    total: u128,
+    /// The group being read, e.g. the one hundred
+    group: u128,
+    last: Kind,
+    /// Whether the group has its hundreds already
+    hundred: bool,
+    /// The last scale, as the next one has to be smaller
+    scale: Option<u32>,
+}
+
+impl Compound {
+    /// `None` if `word` can't start a number (`hundred` can't, for one)
+    fn start(word: u32) -> Option<Self> {
+        let last = Kind::of(word);
+        matches!(last, Kind::Zero | Kind::Unit | Kind::Teen | Kind::Tens).then_some(Self {
+            total: 0,
+            group: word.into(),
+            last,
+            hundred: false,
+            scale: None,
+        })
+    }
+
+    /// The number with `word` added, if it can be, with `separator` before it
+    fn and(self, word: u32, separator: &str) -> Option<Self> {
+        let kind = Kind::of(word);
+        let joined = match separator {
+            "-" => self.last == Kind::Tens && kind == Kind::Unit,
+            " " => true,
+            _ => matches!(self.last, Kind::Hundred | Kind::Scale) && kind != Kind::Scale,
+        };
+        let follows = match (self.last, kind) {
+            (Kind::Zero, _) | (Kind::Scale, Kind::Scale) => false,
+            (Kind::Tens, Kind::Unit) => true,
+            (_, Kind::Hundred) => !self.hundred && matches!(self.last, Kind::Unit | Kind::Teen),
+            (_, Kind::Scale) => self.scale.is_none_or(|scale| word < scale),
+            (Kind::Hundred | Kind::Scale, Kind::Unit | Kind::Teen | Kind::Tens) => true,
+            _ => false,
+        };
+        if !(joined && follows) {
+            return None;
+        }
+
+        let mut next = Self { last: kind, ..self };
+        match kind {
+            Kind::Hundred => {
+                next.group *= 100;
+                next.hundred = true;
+            }
+            Kind::Scale => {
+                next.total += next.group * u128::from(word);
+                next.group = 0;
+                next.hundred = false;
+                next.scale = Some(word);
+            }
+            _ => next.group += u128::from(word),
+        }
+
+        Some(next)
+    }
+
+    fn value(&self) -> u128 {
+        self.total + self.group
+    }
+}
+
+/// What can come between the words of a number, the longest first
+const SEPARATORS: [&str; 3] = [" and ", "-", " "];
+
+/// Finds numbers in lines, built once like a [`Recogniser`].
+#[derive(Clone, Debug)]
+pub struct Numbers {
+    words: Recogniser,
+    combine: Combine,
+}
+
+impl Numbers {
+    pub fn new(combine: Combine) -> Self {
+        Self {
+            words: Recogniser::new(NUMBER_WORDS),
+            combine,
+        }
+    }
+
+    /// Finds the words in upper or mixed case too (`Twenty-One`).
+    pub fn ignoring_case(combine: Combine) -> Self {
+        Self {
+            words: Recogniser::ignoring_case(NUMBER_WORDS),
+            combine,
+        }
+    }
+
+    /// All the numbers in the line, left to right, the longest words taken
+    /// where they overlap (`seventeen`, not `seven`). `None` if a numeral
+    /// doesn't fit in a `u128`.
+    pub fn numbers(&self, line: &str) -> Option<Vec<Number>> {
+        // the longest word starting at every byte
+        let mut words: Vec<Option<Token>> = vec![None; line.len()];
+        for token in self.words.tokens(line) {
+            let longest = &mut words[token.start];
+            if longest.is_none_or(|longest| longest.end < token.end) {
+                *longest = Some(token);
+            }
+        }
+
+        let mut numbers = vec![];
+        let mut i = 0;
+        while i < line.len() {
+            let digits = line[i..].bytes().take_while(u8::is_ascii_digit).count();
+            if digits > 0 {
+                numbers.push(Number {
+                    value: line[i..i + digits].parse().ok()?,
+                    start: i,
+                    end: i + digits,
+                });
+                i += digits;
+                continue;
+            }
+
+            let Some(word) = words[i] else {
+                i += line[i..].chars().next().map_or(1, char::len_utf8);
+                continue;
+            };
+            let Some(mut compound) = Compound::start(word.value) else {
+                i = word.end;
+                continue;
+            };
+
+            let mut end = word.end;
+            'words: loop {
+                for separator in SEPARATORS {
+                    let next = end + separator.len();
+                    let Some(rest) = line.get(end..next) else {
+                        continue;
+                    };
+                    if !rest.eq_ignore_ascii_case(separator) {
+                        continue;
+                    }
+                    let Some(word) = words.get(next).copied().flatten() else {
+                        continue;
+                    };
+                    if let Some(longer) = compound.and(word.value, separator) {
+                        (compound, end) = (longer, word.end);
+                        continue 'words;
+                    }
+                }
+                break;
+            }
+
+            numbers.push(Number {
+                value: compound.value(),
+                start: i,
+                end,
+            });
+            i = end;
+        }
+
+        Some(numbers)
+    }
+}
+
+impl Calibrate for Numbers {
+    fn calibration_value(&self, line: &str) -> Option<u128> {
+        let numbers = self.numbers(line)?;
+        let (Some(first), Some(last)) = (numbers.first(), numbers.last()) else {
+            return Some(0);
+        };
+        trace!("{line:?}, first: {first:?}, last: {last:?}");
+
+        self.combine.apply(first.value, last.value)
+    }
+}
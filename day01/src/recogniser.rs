@@ -0,0 +1,239 @@
+//! Finding number words (and digits) in a line, all of them at once, in a
+//! single pass, however they overlap: an Aho-Corasick automaton over the
+//! words of a vocabulary.
+
+use std::char::ToLowercase;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::str::CharIndices;
+
+/// A word of the vocabulary found in a line, `start..end` being its bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Token {
+    pub value: u32,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Token {
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct State {
+    next: HashMap<char, usize>,
+    /// The longest proper suffix of this state's prefix that's also a prefix
+    /// of some word
+    fail: usize,
+    /// Words ending here, the ones that end here through `fail` included:
+    /// their values and lengths in (folded) characters
+    words: Vec<(u32, usize)>,
+}
+
+/// Built once from a vocabulary of `(word, value)` pairs, e.g. the ones of
+/// [`crate::Language`]s.
+#[derive(Clone, Debug)]
+pub struct Recogniser {
+    states: Vec<State>,
+    ignore_case: bool,
+    /// Characters in the longest word
+    longest: usize,
+}
+
+const ROOT: usize = 0;
+
+impl Recogniser {
+    /// Matches the words exactly. A word that's there twice gets the value
+    /// it's given last.
+    pub fn new<'a>(vocabulary: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self::build(vocabulary, false)
+    }
+
+    /// Matches the words in upper, lower or any mixed case, in any script
+    /// that has case: `EIGHT`, `Fünf`, `PIĘĆ`.
+    pub fn ignoring_case<'a>(vocabulary: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self::build(vocabulary, true)
+    }
+
+    fn build<'a>(vocabulary: impl IntoIterator<Item = (&'a str, u32)>, ignore_case: bool) -> Self {
+        let mut states = vec![State::default()];
+        let mut longest = 0;
+
+        for (word, value) in vocabulary {
+            let mut state = ROOT;
+            let mut len = 0;
+            for ch in word.chars().flat_map(|ch| Folded::new(ch, ignore_case)) {
+                state = match states[state].next.get(&ch) {
+                    Some(&next) => next,
+                    None => {
+                        states.push(State::default());
+                        let next = states.len() - 1;
+                        states[state].next.insert(ch, next);
+                        next
+                    }
+                };
+                len += 1;
+            }
+            if state != ROOT {
+                states[state].words = vec![(value, len)];
+                longest = longest.max(len);
+            }
+        }
+
+        // breadth first, so that the fail links of shallower states are
+        // done by the time the deeper ones need them
+        let mut queue: VecDeque<usize> = states[ROOT].next.values().copied().collect();
+        while let Some(state) = queue.pop_front() {
+            let edges: Vec<(char, usize)> = states[state]
+                .next
+                .iter()
+                .map(|(&ch, &next)| (ch, next))
+                .collect();
+            for (ch, next) in edges {
+                let mut fail = states[state].fail;
+                let fail = loop {
+                    match states[fail].next.get(&ch) {
+                        Some(&target) => break target,
+                        None if fail == ROOT => break ROOT,
+                        None => fail = states[fail].fail,
+                    }
+                };
+                states[next].fail = fail;
+                let inherited = states[fail].words.clone();
+                states[next].words.extend(inherited);
+                queue.push_back(next);
+            }
+        }
+
+        Self {
+            states,
+            ignore_case,
+            longest,
+        }
+    }
+
+    fn step(&self, mut state: usize, ch: char) -> usize {
+        loop {
+            if let Some(&next) = self.states[state].next.get(&ch) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// All the words in `line`, overlapping ones too, in the order they end.
+    pub fn tokens<'a>(&'a self, line: &'a str) -> Tokens<'a> {
+        Tokens {
+            recogniser: self,
+            line: line.char_indices(),
+            folded: Folded::Exact(None),
+            span: (0, 0),
+            starts: VecDeque::with_capacity(self.longest),
+            state: ROOT,
+            word: 0,
+        }
+    }
+
+    /// The word that starts first and the one that ends last, the longer
+    /// ones for ties. They may be the same one, or overlap (`eightwo`).
+    pub fn first_and_last(&self, line: &str) -> Option<(Token, Token)> {
+        self.tokens(line).fold(None, |found, token| match found {
+            None => Some((token, token)),
+            Some((first, last)) => {
+                let first = match token.start.cmp(&first.start) {
+                    Ordering::Less => token,
+                    Ordering::Equal if token.len() > first.len() => token,
+                    _ => first,
+                };
+                let last = match token.end.cmp(&last.end) {
+                    Ordering::Greater => token,
+                    Ordering::Equal if token.len() > last.len() => token,
+                    _ => last,
+                };
+                Some((first, last))
+            }
+        })
+    }
+}
+
+/// A character as the automaton sees it, which is more than one when
+/// lowercasing expands it (`İ` is `i̇`)
+enum Folded {
+    Exact(Option<char>),
+    Lower(ToLowercase),
+}
+
+impl Folded {
+    fn new(ch: char, ignore_case: bool) -> Self {
+        if ignore_case {
+            Folded::Lower(ch.to_lowercase())
+        } else {
+            Folded::Exact(Some(ch))
+        }
+    }
+}
+
+impl Iterator for Folded {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Folded::Exact(ch) => ch.take(),
+            Folded::Lower(chars) => chars.next(),
+        }
+    }
+}
+
+/// See [`Recogniser::tokens`]
+pub struct Tokens<'a> {
+    recogniser: &'a Recogniser,
+    line: CharIndices<'a>,
+    /// What's left of the current character of the line
+    folded: Folded,
+    /// Bytes of the current character of the line
+    span: (usize, usize),
+    /// Where the characters the automaton saw last start in the line, as
+    /// many as there are in the longest word
+    starts: VecDeque<usize>,
+    state: usize,
+    /// Words of `state` yielded so far
+    word: usize,
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let recogniser = self.recogniser;
+        loop {
+            if let Some(&(value, len)) = recogniser.states[self.state].words.get(self.word) {
+                self.word += 1;
+                return Some(Token {
+                    value,
+                    start: self.starts[self.starts.len() - len],
+                    end: self.span.1,
+                });
+            }
+
+            let ch = loop {
+                if let Some(ch) = self.folded.next() {
+                    break ch;
+                }
+                let (i, ch) = self.line.next()?;
+                self.span = (i, i + ch.len_utf8());
+                self.folded = Folded::new(ch, recogniser.ignore_case);
+            };
+            self.state = recogniser.step(self.state, ch);
+            self.word = 0;
+            if self.starts.len() == recogniser.longest {
+                self.starts.pop_front();
+            }
+            self.starts.push_back(self.span.0);
+        }
+    }
+}
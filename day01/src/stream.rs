@@ -0,0 +1,132 @@
+//! Calibrating inputs too big to read into memory at once, line by line,
+//! optionally in chunks spread over a few threads.
+
+use std::io::{self, BufRead};
+use std::num::NonZeroUsize;
+use std::str;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+
+use crate::{calibration_sum, Calibrate};
+
+/// Roughly how many bytes of whole lines the threads get at a time
+const CHUNK: usize = 1 << 16;
+
+const BOM: char = '\u{feff}';
+
+fn too_big() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "the sum doesn't fit in 128 bits",
+    )
+}
+
+/// `bytes` as text, `line` being the number of its first line
+fn text(bytes: &[u8], line: usize) -> io::Result<&str> {
+    str::from_utf8(bytes).map_err(|err| {
+        let line = line
+            + bytes[..err.valid_up_to()]
+                .iter()
+                .filter(|&&b| b == b'\n')
+                .count();
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("line {line}: not valid UTF-8"),
+        )
+    })
+}
+
+/// Same as [`calibration_sum`], but reading the lines one at a time, so
+/// that only the longest one has to fit in memory.
+pub fn calibration_sum_reader<C: Calibrate + ?Sized>(
+    mut reader: impl BufRead,
+    calibrate: &C,
+) -> io::Result<u128> {
+    let mut sum: u128 = 0;
+    let mut buffer = vec![];
+
+    for number in 1.. {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            break;
+        }
+        let mut line = text(&buffer, number)?.trim_end();
+        if number == 1 {
+            line = line.strip_prefix(BOM).unwrap_or(line);
+        }
+
+        sum = calibrate
+            .calibration_value(line)
+            .and_then(|value| sum.checked_add(value))
+            .ok_or_else(too_big)?;
+    }
+
+    Ok(sum)
+}
+
+/// Same as [`calibration_sum_reader`], with the lines read in chunks that
+/// `threads` threads calibrate while the next ones are being read. At most
+/// two chunks per thread are held in memory at a time.
+pub fn calibration_sum_parallel<C: Calibrate + ?Sized>(
+    mut reader: impl BufRead,
+    calibrate: &C,
+    threads: NonZeroUsize,
+) -> io::Result<u128> {
+    // the number of the first line of the chunk, and its lines
+    let (chunks, receiver) = mpsc::sync_channel::<(usize, Vec<u8>)>(threads.get() * 2);
+    let receiver = Mutex::new(receiver);
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.get())
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut sum: io::Result<u128> = Ok(0);
+                    // takes every chunk even after an error, or else reading
+                    // would block on a full channel forever
+                    loop {
+                        // not in a `while let`, which would keep the lock
+                        // for the whole of the body
+                        let next = receiver.lock().unwrap().recv();
+                        let Ok((line, chunk)) = next else {
+                            break;
+                        };
+                        sum = sum.and_then(|sum| {
+                            calibration_sum(text(&chunk, line)?, calibrate)
+                                .and_then(|part| sum.checked_add(part))
+                                .ok_or_else(too_big)
+                        });
+                    }
+                    sum
+                })
+            })
+            .collect();
+
+        let mut read = || -> io::Result<()> {
+            let mut line = 1;
+            loop {
+                let mut chunk = Vec::with_capacity(CHUNK);
+                let mut lines = 0;
+                while chunk.len() < CHUNK && reader.read_until(b'\n', &mut chunk)? > 0 {
+                    lines += 1;
+                }
+                if chunk.is_empty() {
+                    return Ok(());
+                }
+                // only fails when all the workers are gone, which they
+                // aren't until the channel is closed
+                let _ = chunks.send((line, chunk));
+                line += lines;
+            }
+        };
+        let read = read();
+        drop(chunks);
+
+        let mut sum: u128 = 0;
+        for worker in workers {
+            let part = worker.join().expect("Calibrating doesn't panic")?;
+            sum = sum.checked_add(part).ok_or_else(too_big)?;
+        }
+        read.map(|()| sum)
+    })
+}
@@ -0,0 +1,162 @@
+//! Words for the digits: the digits themselves, the built-in languages and
+//! files of anyone's own words.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use aoc_common::{lines, ParseError};
+
+/// The digits themselves
+pub const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// One to nine only, like in the puzzle, so `zero` and friends stay letters
+pub const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub const POLISH: [(&str, u32); 9] = [
+    ("jeden", 1),
+    ("dwa", 2),
+    ("trzy", 3),
+    ("cztery", 4),
+    ("pięć", 5),
+    ("sześć", 6),
+    ("siedem", 7),
+    ("osiem", 8),
+    ("dziewięć", 9),
+];
+
+pub const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+pub const SPANISH: [(&str, u32); 9] = [
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    Polish,
+    German,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [
+        Language::English,
+        Language::Polish,
+        Language::German,
+        Language::Spanish,
+    ];
+
+    pub fn words(self) -> &'static [(&'static str, u32)] {
+        match self {
+            Language::English => &ENGLISH,
+            Language::Polish => &POLISH,
+            Language::German => &GERMAN,
+            Language::Spanish => &SPANISH,
+        }
+    }
+
+    fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Polish => "pl",
+            Language::German => "de",
+            Language::Spanish => "es",
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Language::English => "english",
+            Language::Polish => "polish",
+            Language::German => "german",
+            Language::Spanish => "spanish",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// By the name (`polish`) or the code (`pl`), in any case
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+        Language::ALL
+            .into_iter()
+            .find(|language| s == language.to_string() || s == language.code())
+            .ok_or_else(|| {
+                let known: Vec<String> = Language::ALL.iter().map(ToString::to_string).collect();
+                format!(
+                    "unknown language `{s}`, expected one of {}",
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+/// Reads a file of words: a word and its digit on every line, e.g.
+/// `vijf 5`. Blank lines and the ones starting with `#` don't count.
+pub fn parse_words(text: &str) -> Result<Vec<(String, u32)>, ParseError> {
+    lines(text)
+        .filter(|line| !line.is_blank() && !line.text.trim_start().starts_with('#'))
+        .map(|line| {
+            let mut fields = line.span().split_whitespace();
+            let word = fields.next().expect("Blank lines are skipped");
+            let digit = fields
+                .next()
+                .ok_or_else(|| line.span().end().error("a digit after the word"))?;
+            let value = digit
+                .text
+                .parse()
+                .ok()
+                .filter(|value: &u32| *value <= 9)
+                .ok_or_else(|| digit.error("a digit"))?;
+            if let Some(extra) = fields.next() {
+                return Err(extra.error("the end of the line"));
+            }
+
+            Ok((word.text.to_owned(), value))
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+use advent_of_code_2023::{annotate, calibration_sum, Recogniser, Summary, DIGITS, ENGLISH};
+
+const EXAMPLE2: &str = include_str!("../test2.txt");
+
+#[test]
+fn lines_with_their_words_marked() {
+    let recogniser = Recogniser::new(DIGITS.into_iter().chain(ENGLISH));
+    let lines: Vec<String> = annotate("xtwone3four\r\nabc\n", &recogniser)
+        .map(|line| line.to_string())
+        .collect();
+
+    assert_eq!(
+        lines,
+        [
+            "    1   24  xtwone3four\n             ^^^---^^^^",
+            "    2    -  abc  (no digits)",
+        ]
+    );
+}
+
+#[test]
+fn summaries_add_up_to_the_sum() {
+    let recogniser = Recogniser::new(DIGITS.into_iter().chain(ENGLISH));
+    let input = format!("{EXAMPLE2}nothing here\n");
+
+    let mut summary = Summary::default();
+    for line in annotate(&input, &recogniser) {
+        summary.add(&line);
+    }
+    assert_eq!(
+        summary,
+        Summary {
+            sum: 281,
+            lines: 8,
+            without_digits: 1
+        }
+    );
+    assert_eq!(Some(summary.sum), calibration_sum(&input, &recogniser));
+    assert_eq!(
+        summary.to_string(),
+        "Sum: 281 of 8 lines, 1 of them without digits"
+    );
+}
@@ -0,0 +1,21 @@
+use advent_of_code_2023::Day01;
+use aoc_common::{Day, Part};
+
+const DAY: Day = Day::of::<Day01>();
+
+const EXAMPLE1: &str = include_str!("../test1.txt");
+const EXAMPLE2: &str = include_str!("../test2.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1_example1() {
+    assert_eq!(solve(EXAMPLE1, Part::One), "142");
+}
+
+#[test]
+fn part2_example2() {
+    assert_eq!(solve(EXAMPLE2, Part::Two), "281");
+}
@@ -0,0 +1,95 @@
+use std::num::NonZeroUsize;
+
+use advent_of_code_2023::{
+    calibration_sum, calibration_sum_parallel, calibration_sum_reader, Calibrate, Combine, Numbers,
+};
+
+fn values(numbers: &Numbers, line: &str) -> Vec<u128> {
+    let numbers = numbers.numbers(line).unwrap();
+    numbers.iter().map(|number| number.value).collect()
+}
+
+#[test]
+fn compound_words() {
+    let numbers = Numbers::new(Combine::Tens);
+
+    assert_eq!(values(&numbers, "xtwenty-threey"), [23]);
+    assert_eq!(values(&numbers, "one hundred and five"), [105]);
+    assert_eq!(values(&numbers, "one thousand two hundred"), [1_200]);
+    assert_eq!(
+        values(
+            &numbers,
+            "seventeen million four hundred and two thousand ninety-nine"
+        ),
+        [17_402_099]
+    );
+    // not one number, but a few
+    assert_eq!(values(&numbers, "five seven"), [5, 7]);
+    assert_eq!(values(&numbers, "thousand and one"), [1]);
+    // a scale on its own isn't a number
+    assert_eq!(values(&numbers, "one thousand one million"), [1_001]);
+    assert_eq!(values(&numbers, "one million thousand"), [1_000_000]);
+    assert_eq!(values(&numbers, "twenty-thirty zero nine"), [20, 30, 0, 9]);
+}
+
+#[test]
+fn numerals_and_spans() {
+    let numbers = Numbers::new(Combine::Tens);
+    let line = "a1905bforty-twoc07";
+
+    let found = numbers.numbers(line).unwrap();
+    let spans: Vec<_> = found
+        .iter()
+        .map(|number| (number.value, &line[number.start..number.end]))
+        .collect();
+    assert_eq!(spans, [(1905, "1905"), (42, "forty-two"), (7, "07")]);
+    assert_eq!(
+        values(&Numbers::ignoring_case(Combine::Tens), "Twenty-One"),
+        [21]
+    );
+    assert!(values(&numbers, "Twenty-One").is_empty());
+}
+
+#[test]
+fn combining() {
+    let line = "twenty-three and 7 and one hundred";
+
+    assert_eq!(
+        Numbers::new(Combine::Tens).calibration_value(line),
+        Some(23 * 10 + 100)
+    );
+    assert_eq!(
+        Numbers::new(Combine::Concat).calibration_value(line),
+        Some(23_100)
+    );
+    assert_eq!(Combine::Concat.apply(4, 0), Some(40));
+    assert_eq!(
+        Numbers::new(Combine::Concat).calibration_value("nothing"),
+        Some(0)
+    );
+    assert_eq!("concat".parse(), Ok(Combine::Concat));
+    assert!("sum".parse::<Combine>().is_err());
+}
+
+#[test]
+fn sums_past_32_bits() {
+    let numbers = Numbers::new(Combine::Concat);
+    let input = "4000000000 and 1\n".repeat(3);
+
+    assert_eq!(calibration_sum(&input, &numbers), Some(3 * 40_000_000_001));
+    assert_eq!(
+        calibration_sum_reader(input.as_bytes(), &numbers).unwrap(),
+        3 * 40_000_000_001
+    );
+    let threads = NonZeroUsize::new(2).unwrap();
+    assert_eq!(
+        calibration_sum_parallel(input.as_bytes(), &numbers, threads).unwrap(),
+        3 * 40_000_000_001
+    );
+
+    let huge = u128::MAX.to_string();
+    assert_eq!(numbers.calibration_value(&huge), None);
+    assert_eq!(numbers.calibration_value(&format!("{huge}0")), None);
+    let err = calibration_sum_reader(format!("{huge}\n").as_bytes(), &numbers).unwrap_err();
+    assert_eq!(err.to_string(), "the sum doesn't fit in 128 bits");
+}
@@ -0,0 +1,39 @@
+use advent_of_code_2023::{Recogniser, Token, DIGITS, ENGLISH};
+
+fn recogniser() -> Recogniser {
+    Recogniser::new(DIGITS.into_iter().chain(ENGLISH))
+}
+
+fn first_and_last(line: &str) -> Option<(u32, u32)> {
+    recogniser()
+        .first_and_last(line)
+        .map(|(first, last)| (first.value, last.value))
+}
+
+#[test]
+fn overlapping_words() {
+    assert_eq!(first_and_last("eightwo"), Some((8, 2)));
+    assert_eq!(first_and_last("twone"), Some((2, 1)));
+    assert_eq!(first_and_last("xtwone3four"), Some((2, 4)));
+    assert_eq!(first_and_last("oneight"), Some((1, 8)));
+    assert_eq!(first_and_last("sevenine"), Some((7, 9)));
+}
+
+#[test]
+fn words_at_the_ends_of_lines() {
+    assert_eq!(first_and_last("one"), Some((1, 1)));
+    assert_eq!(first_and_last("two1nine"), Some((2, 9)));
+    assert_eq!(first_and_last("7pqrstsixteen"), Some((7, 6)));
+    assert_eq!(first_and_last("on"), None);
+    assert_eq!(first_and_last(""), None);
+}
+
+#[test]
+fn tokens_with_their_spans() {
+    let tokens: Vec<(u32, usize, usize)> = recogniser()
+        .tokens("ttwonee3")
+        .map(|Token { value, start, end }| (value, start, end))
+        .collect();
+
+    assert_eq!(tokens, [(2, 1, 4), (1, 3, 6), (3, 7, 8)]);
+}
@@ -0,0 +1,57 @@
+use std::num::NonZeroUsize;
+
+use advent_of_code_2023::{
+    calibration_sum, calibration_sum_parallel, calibration_sum_reader, Recogniser, DIGITS, ENGLISH,
+};
+
+const EXAMPLE2: &str = include_str!("../test2.txt");
+
+fn recogniser() -> Recogniser {
+    Recogniser::new(DIGITS.into_iter().chain(ENGLISH))
+}
+
+fn threads(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+#[test]
+fn same_sum_line_by_line() {
+    let input = format!("\u{feff}{}", EXAMPLE2.replace('\n', "\r\n"));
+
+    assert_eq!(
+        calibration_sum_reader(input.as_bytes(), &recogniser()).unwrap(),
+        281
+    );
+    assert_eq!(calibration_sum(&input, &recogniser()), Some(281));
+}
+
+#[test]
+fn same_sum_on_many_threads() {
+    // plenty of chunks, some with `\r\n`
+    let input: String = (0..5_000)
+        .map(|n| {
+            if n % 2 == 0 {
+                EXAMPLE2.to_owned()
+            } else {
+                EXAMPLE2.replace('\n', "\r\n")
+            }
+        })
+        .collect();
+
+    for n in [1, 3, 8] {
+        let sum = calibration_sum_parallel(input.as_bytes(), &recogniser(), threads(n)).unwrap();
+        assert_eq!(sum, 281 * 5_000);
+    }
+}
+
+#[test]
+fn invalid_text_is_an_error() {
+    let mut input = EXAMPLE2.repeat(2_000).into_bytes();
+    input.extend(b"one\xff\n");
+    let line = 7 * 2_000 + 1;
+
+    let err = calibration_sum_reader(&input[..], &recogniser()).unwrap_err();
+    assert_eq!(err.to_string(), format!("line {line}: not valid UTF-8"));
+    let err = calibration_sum_parallel(&input[..], &recogniser(), threads(2)).unwrap_err();
+    assert_eq!(err.to_string(), format!("line {line}: not valid UTF-8"));
+}
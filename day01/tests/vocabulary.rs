@@ -0,0 +1,68 @@
+use advent_of_code_2023::{calibration_sum, parse_words, Language, Recogniser, Token, DIGITS};
+
+fn recogniser(languages: &[Language], ignore_case: bool) -> Recogniser {
+    let vocabulary = DIGITS.into_iter().chain(
+        languages
+            .iter()
+            .flat_map(|language| language.words().iter().copied()),
+    );
+    if ignore_case {
+        Recogniser::ignoring_case(vocabulary)
+    } else {
+        Recogniser::new(vocabulary)
+    }
+}
+
+#[test]
+fn languages() {
+    let polish = recogniser(&[Language::Polish], false);
+    assert_eq!(
+        calibration_sum("dziewięćdwa\nxsześć7\n", &polish),
+        Some(92 + 67)
+    );
+
+    let everything = recogniser(&Language::ALL, false);
+    assert_eq!(
+        calibration_sum("fünfxnueve\ntwodrei", &everything),
+        Some(59 + 23)
+    );
+
+    assert_eq!("PL".parse(), Ok(Language::Polish));
+    assert_eq!("german".parse(), Ok(Language::German));
+    assert!("klingon".parse::<Language>().is_err());
+}
+
+#[test]
+fn any_case_keeps_the_spans_of_the_line() {
+    let german = recogniser(&[Language::German], true);
+    let line = "ẞFÜNFx";
+
+    let tokens: Vec<Token> = german.tokens(line).collect();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        (tokens[0].value, &line[tokens[0].start..tokens[0].end]),
+        (5, "FÜNF")
+    );
+    assert_eq!(
+        calibration_sum("PIĘĆ\nSiedem", &recogniser(&[Language::Polish], true)),
+        Some(55 + 77)
+    );
+    assert_eq!(
+        calibration_sum("PIĘĆ\nSiedem", &recogniser(&[Language::Polish], false)),
+        Some(0)
+    );
+}
+
+#[test]
+fn own_words() {
+    let words = parse_words("# Dutch\n\nvijf 5\r\ntwee 2\n").unwrap();
+    assert_eq!(words, [("vijf".to_owned(), 5), ("twee".to_owned(), 2)]);
+
+    let err = parse_words("vijf 5\nzes\n").unwrap_err();
+    assert_eq!(
+        (err.line, err.expected.as_str()),
+        (2, "a digit after the word")
+    );
+    let err = parse_words("tien 10").unwrap_err();
+    assert_eq!((err.column, err.token.as_str()), (6, "10"));
+}
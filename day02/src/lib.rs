@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use aoc_common::{lines, Error, GenRng, Generate, Line, ParseError, Size, Solution};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+#[derive(Debug)]
+struct GameSet {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+impl GameSet {
+    fn new() -> GameSet {
+        GameSet {
+            red: 0,
+            green: 0,
+            blue: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    sets: Vec<GameSet>,
+}
+
+fn parse_line(line: Line) -> Result<Option<Game>, ParseError> {
+    // line format:
+    // Game N: X blue, X red; 1 red, 2 green, 6 blue; 2 green
+    if line.is_blank() {
+        return Ok(None);
+    }
+
+    let line = line.span().trim();
+
+    // strategy:
+    // 1: split on `:`
+    // 2: split the 2nd half on `;` and strip
+    let (game, sets) = line
+        .split_once(":")
+        .ok_or_else(|| line.end().error("`:` after the game id"))?;
+    let id = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| game.error("`Game <id>`"))?
+        .trim()
+        .parse::<i32>("a game id")?;
+    let sets = sets
+        .split(';')
+        .map(|e| {
+            let mut game = GameSet::new();
+            for instruction in e.split(',') {
+                let mut s = instruction.split_whitespace();
+                let count_span = s
+                    .next()
+                    .ok_or_else(|| instruction.end().error("a number of cubes"))?;
+                let count = count_span.parse::<i32>("a number of cubes")?;
+                let color = s
+                    .next()
+                    .ok_or_else(|| instruction.end().error("a cube color"))?;
+                let total = match color.text {
+                    "blue" => &mut game.blue,
+                    "red" => &mut game.red,
+                    "green" => &mut game.green,
+                    _ => return Err(color.error("`red`, `green` or `blue`")),
+                };
+                *total = total
+                    .checked_add(count)
+                    .ok_or_else(|| count_span.error("a number of cubes that fits in 32 bits"))?;
+                if let Some(extra) = s.next() {
+                    return Err(extra.error("`,` or `;`"));
+                }
+            }
+            Ok(game)
+        })
+        .collect::<Result<Vec<GameSet>, ParseError>>()?;
+
+    Ok(Some(Game { id, sets }))
+}
+
+fn parse_contents(contents: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::<Game>::with_capacity(contents.lines().count());
+
+    for line in lines(contents) {
+        if let Some(game) = parse_line(line)? {
+            games.push(game);
+        }
+    }
+
+    Ok(games)
+}
+
+fn is_possible(game_set: &GameSet) -> bool {
+    game_set.red <= 12 && game_set.green <= 13 && game_set.blue <= 14
+}
+
+// Both of those return `None` when the answer doesn't fit in an `i32`
+fn sum_possible_game_ids(games: &[Game]) -> Option<i32> {
+    let mut sum: i32 = 0;
+    for game in games.iter() {
+        let mut should_add = true;
+        for set in game.sets.iter() {
+            if !is_possible(set) {
+                should_add = false;
+                break;
+            }
+        }
+        if should_add {
+            sum = sum.checked_add(game.id)?;
+        }
+    }
+
+    Some(sum)
+}
+
+fn min_cubes(game: &Game) -> GameSet {
+    let mut res_set = GameSet::new();
+    for set in game.sets.iter() {
+        if set.red > res_set.red {
+            res_set.red = set.red;
+        }
+        if set.blue > res_set.blue {
+            res_set.blue = set.blue;
+        }
+        if set.green > res_set.green {
+            res_set.green = set.green;
+        }
+    }
+
+    res_set
+}
+
+fn game_power(game_set: &GameSet) -> Option<i32> {
+    game_set
+        .red
+        .checked_mul(game_set.green)?
+        .checked_mul(game_set.blue)
+}
+
+fn sum_powers(games: &[Game]) -> Option<i32> {
+    let mut sum: i32 = 0;
+    for game in games.iter() {
+        sum = sum.checked_add(game_power(&min_cubes(game))?)?;
+    }
+
+    Some(sum)
+}
+
+fn too_big() -> Error {
+    Error::NoSolution("the answer doesn't fit in 32 bits".to_owned())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_contents(input)?)
+    }
+
+    fn part1(games: &Self::Input) -> Result<impl Display, Error> {
+        sum_possible_game_ids(games).ok_or_else(too_big)
+    }
+
+    fn part2(games: &Self::Input) -> Result<impl Display, Error> {
+        sum_powers(games).ok_or_else(too_big)
+    }
+}
+
+/// `count` games of up to `length` sets of cubes
+impl Generate for Day02 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let mut input = String::new();
+        for id in 1..=size.count {
+            let sets = (0..rng.gen_range(1..=size.length.max(1)))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(rng);
+                    colors[..rng.gen_range(1..=colors.len())]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            input.push_str(&format!("Game {id}: {sets}\n"));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_common::{Day, Error, ParseError, Part};
+use day2::Day02;
+
+const DAY: Day = Day::of::<Day02>();
+
+#[test]
+fn counts_overflowing_while_parsing() {
+    let err = DAY
+        .solve("Game 1: 2147483647 red, 1 red", Part::One)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::Parse(ParseError::new(
+            1,
+            25,
+            "1",
+            "a number of cubes that fits in 32 bits"
+        ))
+    );
+}
+
+#[test]
+fn answers_overflowing() {
+    let power = "Game 1: 100000 red, 100000 green, 100000 blue";
+    assert!(matches!(
+        DAY.solve(power, Part::Two),
+        Err(Error::NoSolution(_))
+    ));
+
+    let ids = "Game 2147483647: 1 red\nGame 1: 1 red";
+    assert!(matches!(
+        DAY.solve(ids, Part::One),
+        Err(Error::NoSolution(_))
+    ));
+}
+
+#[test]
+fn positions_of_bad_numbers() {
+    let error = |input| DAY.solve(input, Part::One).unwrap_err();
+
+    assert_eq!(
+        error("Game 1: 3 blue\nGame x: 1 red"),
+        Error::Parse(ParseError::new(2, 6, "x", "a game id"))
+    );
+    assert_eq!(
+        error("Game 1: 3 blue; many red"),
+        Error::Parse(ParseError::new(1, 17, "many", "a number of cubes"))
+    );
+}
@@ -0,0 +1,308 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    lines, Cell, Error, GenRng, Generate, Grid, Line, ParseError, Picture, Point, Render, Size,
+    Solution, Style,
+};
+use log::{debug, trace};
+use rand::Rng;
+
+type Schematic = Grid<char>;
+
+#[derive(PartialEq, Clone, Debug)]
+struct Number {
+    value: u32,
+    coords: Point,
+    len: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Gear {
+    coords: Point,
+    ratio: u64,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+enum Mask {
+    Num(Number),
+    Symbol(char),
+    None,
+}
+
+fn mask_view(mask: &Grid<Mask>) -> Grid<char> {
+    mask.map(|cell| match cell {
+        Mask::Num(_) => 'N',
+        Mask::Symbol(s) => *s,
+        Mask::None => '.',
+    })
+}
+
+// every number has to fit into a `u32`, so solving it can't overflow
+fn check_numbers(line: Line) -> Result<(), ParseError> {
+    let mut digits = line.span().chars().peekable();
+    while let Some((start, ch)) = digits.next() {
+        if !ch.is_ascii_digit() {
+            continue;
+        }
+
+        let mut number = String::from(ch);
+        while let Some((_, digit)) = digits.next_if(|(_, ch)| ch.is_ascii_digit()) {
+            number.push(digit);
+        }
+        if number.parse::<u32>().is_err() {
+            return Err(ParseError::new(
+                line.number,
+                start.column,
+                &number,
+                "a number that fits in 32 bits",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_contents(contents: &str) -> Result<Schematic, ParseError> {
+    lines(contents).try_for_each(check_numbers)?;
+
+    let contents = contents
+        .lines()
+        .map(|line| line.trim())
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    Ok(Grid::parse(&contents, Some)?)
+}
+
+fn is_symbol(cell: char) -> bool {
+    !cell.is_ascii_digit() && cell != '.'
+}
+
+fn get_number(i: usize, mut j: usize, schematic: &Schematic, mask: &mut Grid<bool>) -> Number {
+    let mut num = String::new();
+    let coords = Point { i, j };
+
+    while j < schematic.columns() && schematic[Point { i, j }].is_ascii_digit() {
+        num.push(schematic[Point { i, j }]);
+        mask[Point { i, j }] = false;
+        j += 1;
+    }
+
+    trace!("num={num}");
+
+    Number {
+        value: num.parse::<u32>().unwrap(),
+        coords,
+        len: num.len(),
+    }
+}
+
+fn is_part_number(number: &Number, schematic: &Schematic) -> bool {
+    // check all 8 neighbours of every digit of the number
+    (0..number.len).any(|dj| {
+        let digit = Point {
+            i: number.coords.i,
+            j: number.coords.j + dj,
+        };
+        schematic
+            .neighbours8(digit)
+            .any(|neighbour| is_symbol(schematic[neighbour]))
+    })
+}
+
+// return the value of the number, if it has a symbol as a neighbour
+// otherwise, return 0
+fn parse_numbers_neighbours(number: &Number, schematic: &Schematic) -> u32 {
+    if is_part_number(number, schematic) {
+        return number.value;
+    }
+
+    0
+}
+
+/// `None` if the sum doesn't fit in 32 bits
+fn sum_adjacent(schematic: &Schematic) -> Option<u32> {
+    let mut sum: u32 = 0;
+    let mut mask = Grid::new(schematic.rows(), schematic.columns(), true);
+
+    for (point, cell) in schematic.cells() {
+        if mask[point] && cell.is_ascii_digit() {
+            let number = get_number(point.i, point.j, schematic, &mut mask);
+            trace!("found number: {number:?}");
+            sum = sum.checked_add(parse_numbers_neighbours(&number, schematic))?;
+        }
+    }
+
+    Some(sum)
+}
+
+fn get_num(i: usize, mut j: usize, schematic: &Schematic, mask: &mut Grid<Mask>) -> Number {
+    let mut num = String::new();
+    let coords = Point { i, j };
+
+    while j < schematic.columns() && schematic[Point { i, j }].is_ascii_digit() {
+        num.push(schematic[Point { i, j }]);
+        j += 1;
+    }
+
+    trace!("num={num}");
+
+    let num = Number {
+        value: num.parse::<u32>().unwrap(),
+        coords,
+        len: num.len(),
+    };
+    for dj in (coords.j)..(j) {
+        mask[Point { i, j: dj }] = Mask::Num(num.clone());
+    }
+    num
+}
+
+fn get_matrix(schematic: &Schematic) -> Grid<Mask> {
+    let mut mask = Grid::new(schematic.rows(), schematic.columns(), Mask::None);
+
+    for (point, &cell) in schematic.cells() {
+        if mask[point] == Mask::None && cell.is_ascii_digit() {
+            let number = get_num(point.i, point.j, schematic, &mut mask);
+            trace!("found number: {number:?}");
+        }
+        if mask[point] == Mask::None && cell != '.' {
+            mask[point] = Mask::Symbol(cell);
+        }
+    }
+
+    trace!("mask:\n{}", mask_view(&mask));
+
+    mask
+}
+
+fn get_gear(point: Point, matrix: &Grid<Mask>) -> Option<Gear> {
+    if let Mask::Symbol(_) = matrix[point] {
+        let mut nums = Vec::<Number>::new();
+        for neighbour in matrix.neighbours8(point) {
+            if let Mask::Num(num) = &matrix[neighbour] {
+                if !nums.contains(num) {
+                    trace!("found num {} for gear at {point:?}", num.value);
+                    nums.push(num.clone())
+                }
+            }
+        }
+
+        trace!("nums for gear at {point:?}: {nums:?}");
+
+        if nums.len() == 2 {
+            return Some(Gear {
+                coords: point,
+                ratio: nums[0].value as u64 * nums[1].value as u64,
+            });
+        }
+    }
+    None
+}
+
+fn get_gears(schematic: &Schematic) -> Vec<Gear> {
+    let matrix = get_matrix(schematic);
+    let mut gears = Vec::<Gear>::new();
+    for (point, _) in matrix.cells() {
+        if let Some(gear) = get_gear(point, &matrix) {
+            gears.push(gear);
+        }
+    }
+
+    gears
+}
+
+/// `None` if the sum doesn't fit in 64 bits
+fn sum_gear_ratios(schematic: &Schematic) -> Option<u64> {
+    let mut sum: u64 = 0;
+    let gears = get_gears(schematic);
+
+    debug!("found {} gears", gears.len());
+    trace!("gears: {gears:?}");
+
+    for gear in gears.iter() {
+        sum = sum.checked_add(gear.ratio)?;
+    }
+
+    Some(sum)
+}
+
+fn too_big(bits: u32) -> Error {
+    Error::NoSolution(format!("the sum doesn't fit in {bits} bits"))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_contents(input)?)
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<impl Display, Error> {
+        sum_adjacent(schematic).ok_or_else(|| too_big(32))
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<impl Display, Error> {
+        sum_gear_ratios(schematic).ok_or_else(|| too_big(64))
+    }
+}
+
+/// Part numbers and gears, among all the other numbers and symbols
+impl Render for Day03 {
+    fn render(schematic: &Self::Input) -> Result<Picture, Error> {
+        let matrix = get_matrix(schematic);
+        let gears: Vec<Point> = get_gears(schematic)
+            .iter()
+            .map(|gear| gear.coords)
+            .collect();
+
+        let mut picture = schematic.map(|&tile| Cell::new(tile, Style::Empty));
+        for (point, mask) in matrix.cells() {
+            picture[point].style = match mask {
+                Mask::Num(number) if is_part_number(number, schematic) => Style::PartNumber,
+                Mask::Num(_) => Style::Plain,
+                Mask::Symbol(_) if gears.contains(&point) => Style::Gear,
+                Mask::Symbol(_) => Style::Symbol,
+                Mask::None => Style::Empty,
+            };
+        }
+
+        Ok(picture)
+    }
+}
+
+/// A schematic of `count` rows and `length` columns: mostly dots, with
+/// numbers up to 999 and symbols between them
+impl Generate for Day03 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+        let mut input = String::new();
+        for _ in 0..size.count {
+            let mut row = String::with_capacity(size.length);
+            while row.len() < size.length {
+                match rng.gen_range(0..10) {
+                    0 | 1 => {
+                        let number = rng.gen_range(1..1000).to_string();
+                        let room = size.length - row.len();
+                        row.push_str(&number[..number.len().min(room)]);
+                        // so the next number doesn't glue to this one
+                        if row.len() < size.length {
+                            row.push('.');
+                        }
+                    }
+                    2 => row.push(char::from(SYMBOLS[rng.gen_range(0..SYMBOLS.len())])),
+                    _ => row.push('.'),
+                }
+            }
+            input.push_str(&row);
+            input.push('\n');
+        }
+
+        input
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_common::{lines, Error, GenRng, Generate, ParseError, Size, Solution, Span};
+use log::debug;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct Card {
+    id: u32,
+    winning: HashSet<u32>,
+    yours: HashSet<u32>,
+    wins: u32,
+}
+
+fn parse_numbers(numbers: Span) -> Result<HashSet<u32>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|v| v.parse::<u32>("a number"))
+        .collect()
+}
+
+fn parse_contents(contents: &str) -> Result<Vec<Card>, ParseError> {
+    let mut cards = Vec::<Card>::new();
+
+    for line in lines(contents).filter(|line| !line.is_blank()) {
+        let line = line.span().trim();
+        let (card, numbers) = line
+            .split_once(":")
+            .ok_or_else(|| line.end().error("`:` after the card id"))?;
+
+        let id = card
+            .strip_prefix("Card")
+            .ok_or_else(|| card.error("`Card <id>`"))?
+            .trim()
+            .parse::<u32>("a card id")?;
+
+        let (winning, yours) = numbers
+            .split_once("|")
+            .ok_or_else(|| numbers.end().error("`|` between the number lists"))?;
+
+        let winning = parse_numbers(winning)?;
+        let yours = parse_numbers(yours)?;
+
+        let wins = get_num_of_wins(&winning, &yours);
+
+        cards.push(Card {
+            id,
+            winning,
+            yours,
+            wins,
+        });
+    }
+
+    Ok(cards)
+}
+
+fn get_num_of_wins(winning: &HashSet<u32>, yours: &HashSet<u32>) -> u32 {
+    winning.intersection(yours).collect::<Vec<_>>().len() as u32
+}
+
+// Both of those return `None` when the answer doesn't fit in a `u32`
+fn get_points(card: &Card) -> Option<u32> {
+    match card.wins {
+        0 => Some(0),
+        i => 2u32.checked_pow(i - 1),
+    }
+}
+
+fn sum_points(cards: &[Card]) -> Option<u32> {
+    let mut sum: u32 = 0;
+    for card in cards {
+        sum = sum.checked_add(get_points(card)?)?;
+    }
+
+    Some(sum)
+}
+
+/// Every card wins a copy of the next `wins` cards for each copy of itself
+/// there is, so the copies are counted instead of cloned.
+fn get_total_cards(cards: &[Card]) -> Option<u32> {
+    let mut copies = vec![1u32; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let won = (i + 1)..(i + 1 + card.wins as usize).min(cards.len());
+        for j in won {
+            copies[j] = copies[j].checked_add(copies[i])?;
+        }
+    }
+
+    let total = copies
+        .iter()
+        .try_fold(0u32, |total, &n| total.checked_add(n))?;
+    debug!("total cards: {total}");
+
+    Some(total)
+}
+
+fn too_big() -> Error {
+    Error::NoSolution("the answer doesn't fit in 32 bits".to_owned())
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_contents(input)?)
+    }
+
+    fn part1(cards: &Self::Input) -> Result<impl Display, Error> {
+        sum_points(cards).ok_or_else(too_big)
+    }
+
+    fn part2(cards: &Self::Input) -> Result<impl Display, Error> {
+        get_total_cards(cards).ok_or_else(too_big)
+    }
+}
+
+/// `count` cards with `length` winning numbers and twice as many of yours.
+///
+/// Most of the cards win nothing or little: a card wins copies of the ones
+/// after it, so with more than a win per card on average the number of
+/// copies (and part 2) would grow exponentially.
+impl Generate for Day04 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let length = size.length.max(1);
+        let pool: Vec<u32> = (1..=(3 * length).max(99) as u32).collect();
+        let width = pool.len().to_string().len();
+        let id_width = size.count.to_string().len();
+        let join = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>width$}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let mut input = String::new();
+        for id in 1..=size.count {
+            let numbers: Vec<u32> = pool.choose_multiple(rng, 3 * length).copied().collect();
+            let (winning, others) = numbers.split_at(length);
+
+            let wins = match rng.gen_range(0..20) {
+                0..=9 => 0,
+                10..=15 => 1,
+                16..=18 => 2,
+                _ => rng.gen_range(3..=5),
+            };
+            let wins = wins.min(length);
+            let mut yours = others.to_vec();
+            yours[..wins].copy_from_slice(&winning[..wins]);
+            yours.shuffle(rng);
+
+            input.push_str(&format!(
+                "Card {id:>id_width$}: {} | {}\n",
+                join(winning),
+                join(&yours)
+            ));
+        }
+
+        input
+    }
+}
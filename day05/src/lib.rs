@@ -0,0 +1,239 @@
+use std::fmt::Display;
+use std::ops::Range;
+
+use aoc_common::{paragraphs, Error, GenRng, Generate, Line, ParseError, Size, Solution, Span};
+use log::{debug, trace};
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+type Seed = usize;
+type Location = usize;
+type Offset = i64;
+
+/// Numbers are moved by signed offsets, so they (and the ends of the
+/// ranges) have to fit in an `Offset`
+const MAX_NUMBER: Seed = Offset::MAX as Seed;
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    src_ranges: Vec<Range<Seed>>,
+    offsets: Vec<Offset>,
+}
+
+fn parse_number(span: Span, expected: &str) -> Result<Seed, ParseError> {
+    let number = span.parse::<Seed>(expected)?;
+    if number > MAX_NUMBER {
+        return Err(span.error("a number that fits in 63 bits"));
+    }
+
+    Ok(number)
+}
+
+fn parse_category(category: &[Line]) -> Result<Map, ParseError> {
+    let header = category[0].span();
+    if !header.text.ends_with(" map:") {
+        return Err(header.error("`<source>-to-<destination> map:`"));
+    }
+
+    let ranges = &category[1..];
+
+    let mut src_ranges = Vec::<Range<Seed>>::with_capacity(ranges.len());
+    let mut offsets = Vec::<Offset>::with_capacity(ranges.len());
+
+    for range in ranges.iter() {
+        let line = range.span();
+        let range = line.split_whitespace().collect::<Vec<Span>>();
+        if range.len() != 3 {
+            let extra = range.get(3).copied().unwrap_or(line.end());
+            return Err(extra.error("a range: `<destination> <source> <length>`"));
+        }
+        let src_range_start = parse_number(range[1], "a source range start")?;
+        let dst_range_start = parse_number(range[0], "a destination range start")?;
+        let range_len = parse_number(range[2], "a range length")?;
+        let src_range_end = src_range_start + range_len;
+        if src_range_end.max(dst_range_start + range_len) > MAX_NUMBER {
+            return Err(range[2].error("a range that fits in 63 bits"));
+        }
+
+        src_ranges.push(src_range_start..src_range_end);
+        offsets.push(dst_range_start as Offset - src_range_start as Offset);
+    }
+
+    Ok(Map {
+        src_ranges,
+        offsets,
+    })
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Seed>, Vec<Map>), ParseError> {
+    let mut maps = Vec::<Map>::new();
+
+    let categories = paragraphs(input);
+
+    let seeds_line = categories
+        .first()
+        .map_or(Span::missing(1), |category| category[0].span());
+    let seeds = seeds_line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| seeds_line.error("`seeds: <seed> <seed> ...`"))?;
+
+    let seeds = seeds
+        .split_whitespace()
+        .map(|seed| parse_number(seed, "a seed number"))
+        .collect::<Result<Vec<Seed>, ParseError>>()?;
+    // the seeds come in pairs of a range start and its length
+    if seeds.len() % 2 == 1 {
+        return Err(seeds_line.end().error("a range length after the last seed"));
+    }
+    if let Some(extra) = categories[0].get(1) {
+        return Err(extra.span().error("a blank line after the seeds"));
+    }
+
+    let categories = &categories[1..];
+
+    for category in categories.iter() {
+        let map = parse_category(category)?;
+        maps.push(map);
+    }
+
+    debug!("{} seeds, {} maps", seeds.len(), maps.len());
+    trace!("seeds: {seeds:?}");
+    trace!("maps: {maps:?}");
+
+    Ok((seeds, maps))
+}
+
+fn traverse(seed: Seed, maps: &[Map]) -> Location {
+    let mut seed = seed as Offset;
+
+    for (n, map) in maps.iter().enumerate() {
+        let mut offset = 0;
+        for (i, src_range) in map.src_ranges.iter().enumerate() {
+            if src_range.contains(&(seed as Seed)) {
+                offset = map.offsets[i];
+            }
+        }
+        trace!("number after map {n} for {seed} is {}", seed + offset);
+        seed += offset;
+    }
+
+    seed as Seed
+}
+
+fn lowest_location(seeds: &[Seed], maps: &[Map]) -> Location {
+    let mut lowest = Seed::MAX;
+
+    for seed in seeds.iter() {
+        let seed_location = traverse(*seed, maps);
+        debug!("location for seed {seed}: {seed_location}");
+        if seed_location < lowest {
+            lowest = seed_location;
+        }
+    }
+
+    lowest
+}
+
+fn get_ranges(seeds: &[Seed]) -> Vec<(Range<Seed>, usize)> {
+    let mut ranges = Vec::<(Range<Seed>, usize)>::with_capacity(seeds.len() / 2);
+    for i in 0..(seeds.len() / 2) {
+        let range_start_idx = 2 * i;
+        let range_len_idx = range_start_idx + 1;
+
+        ranges.push((
+            seeds[range_start_idx]..seeds[range_start_idx] + seeds[range_len_idx],
+            seeds[range_len_idx],
+        ));
+    }
+
+    ranges
+}
+
+fn lowest_location_ranges(seeds: &[Seed], maps: &[Map]) -> Location {
+    let mut lowest = Seed::MAX;
+
+    let ranges = get_ranges(seeds);
+
+    for (range, range_len) in ranges.into_iter() {
+        // if doesnt work: maybe start a new thread for every range?
+        debug!("calculating for seeds in range {range:?} (length: {range_len})");
+        for seed in range {
+            let seed_location = traverse(seed, maps);
+            trace!("location for seed {seed}/{range_len}: {seed_location}");
+            if seed_location < lowest {
+                lowest = seed_location;
+            }
+        }
+    }
+
+    lowest
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Seed>, Vec<Map>);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((seeds, maps): &Self::Input) -> Result<impl Display, Error> {
+        Ok(lowest_location(seeds, maps))
+    }
+
+    fn part2((seeds, maps): &Self::Input) -> Result<impl Display, Error> {
+        Ok(lowest_location_ranges(seeds, maps))
+    }
+}
+
+/// An almanac of `count` seed ranges and maps of `length` ranges each.
+/// Like in the puzzle, the source ranges of a map never overlap.
+impl Generate for Day05 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        const CATEGORIES: [&str; 8] = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+        let length = size.length.max(1);
+        // all the numbers stay below it
+        let universe = 100 * length;
+
+        let seeds: Vec<String> = (0..size.count)
+            .flat_map(|_| [rng.gen_range(0..universe), rng.gen_range(1..=universe / 10)])
+            .map(|number| number.to_string())
+            .collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for names in CATEGORIES.windows(2) {
+            input.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+
+            // distinct cuts, paired up into ranges that can't overlap
+            let mut cuts = index::sample(rng, universe, 2 * length).into_vec();
+            cuts.sort_unstable();
+            let mut ranges: Vec<String> = cuts
+                .chunks(2)
+                .map(|cut| {
+                    let destination = rng.gen_range(0..universe);
+                    format!("{destination} {} {}", cut[0], cut[1] - cut[0])
+                })
+                .collect();
+            ranges.shuffle(rng);
+
+            for range in ranges {
+                input.push_str(&range);
+                input.push('\n');
+            }
+        }
+
+        input
+    }
+}
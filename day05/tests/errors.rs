@@ -0,0 +1,19 @@
+use aoc_common::{Day, Error, ParseError, Part};
+use day5::Day05;
+
+const DAY: Day = Day::of::<Day05>();
+
+#[test]
+fn seed_without_a_range_length() {
+    let input = "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n";
+
+    assert_eq!(
+        DAY.solve(input, Part::Two).unwrap_err(),
+        Error::Parse(ParseError::new(
+            1,
+            16,
+            "",
+            "a range length after the last seed"
+        ))
+    );
+}
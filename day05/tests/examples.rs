@@ -0,0 +1,27 @@
+use aoc_common::input::saved_on_windows;
+use aoc_common::{Day, Part};
+use day5::Day05;
+
+const DAY: Day = Day::of::<Day05>();
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1() {
+    assert_eq!(solve(EXAMPLE, Part::One), "35");
+}
+
+#[test]
+fn part2() {
+    assert_eq!(solve(EXAMPLE, Part::Two), "46");
+}
+
+#[test]
+fn windows_line_endings() {
+    assert_eq!(solve(&saved_on_windows(EXAMPLE), Part::One), "35");
+    assert_eq!(solve(&saved_on_windows(EXAMPLE), Part::Two), "46");
+}
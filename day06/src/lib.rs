@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use aoc_common::{lines, Error, GenRng, Generate, ParseError, Size, Solution, Span};
+use log::{debug, trace};
+use rand::Rng;
+
+type Unit = u64;
+
+#[derive(Clone, Debug)]
+pub struct Race {
+    time: Unit,
+    record_distance: Unit,
+}
+
+fn parse_line(line: Span, label: &str) -> Result<Vec<Unit>, ParseError> {
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| line.error(&format!("`{label} <number> <number> ...`")))?;
+
+    numbers
+        .split_whitespace()
+        .map(|token| token.parse::<Unit>("a number"))
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = lines(input).filter(|line| !line.is_blank());
+
+    let times_line = lines.next().map_or(Span::missing(1), |line| line.span());
+    let times = parse_line(times_line, "Time:")?;
+
+    let distances_line = lines
+        .next()
+        .map_or(Span::missing(times_line.line + 1), |line| line.span());
+    let distances = parse_line(distances_line, "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(distances_line
+            .end()
+            .error(&format!("{} distances", times.len())));
+    }
+    if let Some(extra) = lines.next() {
+        return Err(extra.span().error("end of the input"));
+    }
+
+    let mut races = Vec::with_capacity(times.len());
+    for (time, distance) in times.into_iter().zip(distances) {
+        races.push(Race {
+            time,
+            record_distance: distance,
+        });
+    }
+
+    Ok(races)
+}
+
+fn beats_record(time: Unit, race: &Race) -> bool {
+    let speed = time;
+    // a distance that doesn't even fit in 64 bits beats any record
+    let Some(distance) = (race.time - time).checked_mul(speed) else {
+        return true;
+    };
+
+    trace!(
+        "speed: {speed}, distance traveled: {distance}, record: {}",
+        race.record_distance
+    );
+
+    distance > race.record_distance
+}
+
+/// `None` if the product doesn't fit in 64 bits
+fn multiply_record_beating_ways(races: &[Race]) -> Option<Unit> {
+    let mut mul: Unit = 1;
+
+    for race in races {
+        let (mut left, mut right) = (1, race.time.saturating_sub(1));
+        for time in left..=right {
+            if beats_record(time, race) {
+                left = time;
+                break;
+            }
+        }
+
+        for time in (left..=right).rev() {
+            if beats_record(time, race) {
+                right = time;
+                break;
+            }
+        }
+
+        debug!("race: {race:?}, least time: {left}, most time: {right}");
+
+        mul = mul.checked_mul((right + 1).saturating_sub(left).max(1))?;
+    }
+
+    Some(mul)
+}
+
+fn too_many_ways() -> Error {
+    Error::NoSolution("the number of ways doesn't fit in 64 bits".to_owned())
+}
+
+fn concat_numbers(numbers: impl Iterator<Item = Unit>) -> Option<Unit> {
+    numbers
+        .map(|number| number.to_string())
+        .collect::<String>()
+        .parse::<Unit>()
+        .ok()
+}
+
+/// The numbers on the sheet are actually one number with bad kerning
+fn merge_races(races: &[Race]) -> Result<Race, Error> {
+    let too_big = || Error::NoSolution("the merged race doesn't fit in 64 bits".to_owned());
+
+    Ok(Race {
+        time: concat_numbers(races.iter().map(|race| race.time)).ok_or_else(too_big)?,
+        record_distance: concat_numbers(races.iter().map(|race| race.record_distance))
+            .ok_or_else(too_big)?,
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(races: &Self::Input) -> Result<impl Display, Error> {
+        multiply_record_beating_ways(races).ok_or_else(too_many_ways)
+    }
+
+    fn part2(races: &Self::Input) -> Result<impl Display, Error> {
+        multiply_record_beating_ways(&[merge_races(races)?]).ok_or_else(too_many_ways)
+    }
+}
+
+/// `count` races lasting up to `length` milliseconds, all of their records
+/// possible to beat
+impl Generate for Day06 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let mut times = Vec::with_capacity(size.count);
+        let mut distances = Vec::with_capacity(size.count);
+        for _ in 0..size.count {
+            let time: Unit = rng.gen_range(2..=size.length.max(2) as Unit);
+            let best = (time / 2) * (time - time / 2);
+            times.push(time.to_string());
+            distances.push(rng.gen_range(0..best).to_string());
+        }
+
+        let width = distances.iter().map(String::len).max().unwrap_or(0);
+        let row = |numbers: &[String]| {
+            numbers
+                .iter()
+                .map(|number| format!("  {number:>width$}"))
+                .collect::<String>()
+        };
+
+        format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances))
+    }
+}
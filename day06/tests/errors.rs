@@ -0,0 +1,20 @@
+use aoc_common::{Day, Error, ParseError, Part};
+use day6::Day06;
+
+const DAY: Day = Day::of::<Day06>();
+
+fn error(input: &str) -> Error {
+    DAY.solve(input, Part::One).unwrap_err()
+}
+
+#[test]
+fn positions_of_bad_numbers() {
+    assert_eq!(
+        error("Time:      7  x   30\nDistance:  9  40  200\n"),
+        Error::Parse(ParseError::new(1, 15, "x", "a number"))
+    );
+    assert_eq!(
+        error("Time:      7  15   30\nDistance:  9  4o  200\n"),
+        Error::Parse(ParseError::new(2, 15, "4o", "a number"))
+    );
+}
@@ -0,0 +1,482 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use aoc_common::{lines, Error, GenRng, Generate, ParseError, Size, Solution, Span};
+use log::trace;
+use rand::Rng;
+
+type Card = char;
+type Strength = u8;
+
+const CARDS_LEN: usize = 13;
+const HAND_LEN: usize = 5;
+const CARDS: [Card; CARDS_LEN] = [
+    'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+];
+const CARDS_WITHOUT_JOKERS: [Card; CARDS_LEN] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+const JOKER_INDEX: usize = 0;
+const JOKER: char = CARDS[JOKER_INDEX];
+
+trait CardStrength {
+    fn strength(self) -> Strength;
+    fn strength_without_jokers(self) -> Strength;
+}
+
+impl CardStrength for Card {
+    fn strength(self) -> Strength {
+        CARDS.into_iter().position(|c| c == self).unwrap_or(0) as Strength
+    }
+
+    fn strength_without_jokers(self) -> Strength {
+        CARDS_WITHOUT_JOKERS
+            .into_iter()
+            .position(|c| c == self)
+            .unwrap_or(0) as Strength
+    }
+}
+
+/// Part 1 plays with regular `J`acks, part 2 with `J`okers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rules {
+    Standard,
+    Jokers,
+}
+
+impl Rules {
+    fn strength(self, card: Card) -> Strength {
+        match self {
+            Rules::Standard => card.strength_without_jokers(),
+            Rules::Jokers => card.strength(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
+enum CardKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl CardKind {
+    fn cmp(&self, other: &CardKind) -> Ordering {
+        #[allow(clippy::comparison_chain)]
+        if self > other {
+            return Ordering::Greater;
+        } else if self < other {
+            return Ordering::Less;
+        }
+        Ordering::Equal
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Hand {
+    cards: [Card; HAND_LEN],
+    kind: CardKind,
+    bid: u32,
+}
+
+fn histogram(cards: &[Card; HAND_LEN]) -> [Strength; CARDS_LEN] {
+    let mut histogram: [Strength; CARDS_LEN] = [0; CARDS_LEN];
+
+    for card in cards {
+        histogram[card.strength() as usize] += 1;
+    }
+
+    trace!("histogram for cards {cards:?}: {histogram:?}");
+
+    histogram
+}
+
+fn get_cards_and_bid(line: Span) -> Result<([Card; HAND_LEN], u32), ParseError> {
+    let mut cards = ['0'; HAND_LEN];
+    let mut tokens = line.split_whitespace();
+
+    let hand = tokens
+        .next()
+        .ok_or_else(|| line.end().error("a hand of 5 cards"))?;
+    let bid = tokens.next().ok_or_else(|| hand.end().error("a bid"))?;
+    if let Some(extra) = tokens.next() {
+        return Err(extra.error("end of the line"));
+    }
+
+    let bid = bid.parse("a bid")?;
+
+    let mut chars = hand.chars();
+    for card in cards.iter_mut() {
+        let (span, ch) = chars
+            .next()
+            .ok_or_else(|| hand.end().error("a hand of 5 cards"))?;
+        if !CARDS.contains(&ch) {
+            return Err(span.error("a card (`AKQJT98765432`)"));
+        }
+        *card = ch;
+    }
+    if let Some((span, _)) = chars.next() {
+        return Err(span.error("a hand of 5 cards"));
+    }
+
+    Ok((cards, bid))
+}
+
+/// XDXDDDDXDXDXDXDDD
+fn get_hand(cards: [Card; HAND_LEN], bid: u32) -> Hand {
+    let histogram = histogram(&cards);
+
+    let (mut pairs, mut seen_three) = (0, false);
+
+    for (i, times) in histogram.iter().enumerate() {
+        let current_card = CARDS[i];
+        match times {
+            // [PPPPP] or [JJJJJ] => FiveOfAKind 1 or FiveOfAKind 6
+            5 => {
+                return Hand {
+                    cards,
+                    kind: CardKind::FiveOfAKind,
+                    bid,
+                }
+            }
+            4 => {
+                let remaining_card = CARDS[histogram.iter().position(|&card| card == 1).unwrap()];
+
+                // [PPPP][J] or [P][JJJJ] => FiveOfAKind 2 or FiveOfAKind 5
+                if current_card == JOKER || remaining_card == JOKER {
+                    return Hand {
+                        cards,
+                        kind: CardKind::FiveOfAKind,
+                        bid,
+                    };
+                }
+                // [PPPP][Q] => FourOfAKind 1
+                return Hand {
+                    cards,
+                    kind: CardKind::FourOfAKind,
+                    bid,
+                };
+            }
+            3 => seen_three = true,
+            2 => pairs += 1,
+            _ => continue,
+        };
+    }
+
+    let joker_times = histogram[JOKER_INDEX];
+
+    // 1 three
+    if seen_three {
+        // 1 pair
+        if pairs == 1 {
+            // jokers are the three or the pair
+            if joker_times == 3 || joker_times == 2 {
+                // [PP][JJJ] or [PPP][JJ] => FiveOfAKind 3 or FiveOfAKind 4
+                return Hand {
+                    cards,
+                    kind: CardKind::FiveOfAKind,
+                    bid,
+                };
+            }
+
+            // 0 jokers
+            // [PPP][QQ] => FullHouse 1
+            return Hand {
+                cards,
+                kind: CardKind::FullHouse,
+                bid,
+            };
+        }
+
+        // 0 pairs, 3 jokers
+        if joker_times == 3 {
+            // [P][JJJ][Q] => FourOfAKind 4
+            return Hand {
+                cards,
+                kind: CardKind::FourOfAKind,
+                bid,
+            };
+        }
+
+        // 0 pairs, 1 joker
+        if joker_times == 1 {
+            // [PPP][J][Q] => FourOfAKind 2
+            return Hand {
+                cards,
+                kind: CardKind::FourOfAKind,
+                bid,
+            };
+        }
+
+        // 0 pairs and 0 jokers
+        // [PPP][Q][R] => ThreeOfAKind 1
+        return Hand {
+            cards,
+            kind: CardKind::ThreeOfAKind,
+            bid,
+        };
+    }
+
+    // 0 threes
+    // 2 pairs of 2
+    if pairs == 2 {
+        // 2 jokers are 1 of the pairs
+        if joker_times == 2 {
+            // [PP][JJ][Q] => FourOfAKind 3
+            return Hand {
+                cards,
+                kind: CardKind::FourOfAKind,
+                bid,
+            };
+        }
+
+        // 1 joker apart from the pairs
+        if joker_times == 1 {
+            // [PP][J][QQ] => FullHouse 2
+            return Hand {
+                cards,
+                kind: CardKind::FullHouse,
+                bid,
+            };
+        }
+
+        // 0 jokers
+        // [PP][QQ][R] => TwoPair 1
+        return Hand {
+            cards,
+            kind: CardKind::TwoPair,
+            bid,
+        };
+    }
+
+    // 0 threes
+    // only 1 pair
+    if pairs == 1 {
+        // 2 jokers as the pair or 1 joker apart from the pair
+        if joker_times == 1 || joker_times == 2 {
+            // [P][JJ][Q][R] or [PP][J][Q][R] => ThreeOfAKind 2 or ThreeOfAKind 3
+            return Hand {
+                cards,
+                kind: CardKind::ThreeOfAKind,
+                bid,
+            };
+        }
+
+        // 0 jokers
+        // [PP][Q][R][S] => OnePair 1
+        return Hand {
+            cards,
+            kind: CardKind::OnePair,
+            bid,
+        };
+    }
+
+    if joker_times == 1 {
+        return Hand {
+            cards,
+            kind: CardKind::OnePair,
+            bid,
+        };
+    }
+
+    // 0 threes
+    // 0 pairs
+    // [P][Q][R][S][T] => HighCard 1
+    Hand {
+        cards,
+        kind: CardKind::HighCard,
+        bid,
+    }
+}
+
+/// Same hand, but with `J` as just a regular card
+fn without_jokers(hand: &Hand) -> Hand {
+    let mut counts = histogram(&hand.cards)
+        .into_iter()
+        .filter(|&times| times > 0)
+        .collect::<Vec<_>>();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    let kind = match counts[..] {
+        [5] => CardKind::FiveOfAKind,
+        [4, ..] => CardKind::FourOfAKind,
+        [3, 2] => CardKind::FullHouse,
+        [3, ..] => CardKind::ThreeOfAKind,
+        [2, 2, ..] => CardKind::TwoPair,
+        [2, ..] => CardKind::OnePair,
+        _ => CardKind::HighCard,
+    };
+
+    Hand {
+        kind,
+        ..hand.clone()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hand>, ParseError> {
+    let mut hands = Vec::<Hand>::with_capacity(input.lines().count());
+
+    for line in lines(input).filter(|line| !line.is_blank()) {
+        let (cards, bid) = get_cards_and_bid(line.span())?;
+        let hand = get_hand(cards, bid);
+
+        hands.push(hand);
+    }
+
+    Ok(hands)
+}
+
+/// Orders hands by their kind first, then card by card.
+/// Hands with the same cards are equal, whatever their bids.
+fn compare_hands(current: &Hand, next: &Hand, rules: Rules) -> Ordering {
+    let comparison = current.kind.cmp(&next.kind);
+    if comparison != Ordering::Equal {
+        return comparison;
+    }
+
+    for (curr_char, next_char) in current.cards.iter().zip(next.cards.iter()) {
+        let comparison = rules.strength(*curr_char).cmp(&rules.strength(*next_char));
+        if comparison != Ordering::Equal {
+            return comparison;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// From the weakest to the strongest hand
+fn rank_hands(hands: &[Hand], rules: Rules) -> Vec<Hand> {
+    let mut hands = hands.to_vec();
+    hands.sort_by(|current, next| compare_hands(current, next, rules));
+
+    hands
+}
+
+/// `None` if the winnings don't fit in 32 bits
+fn total_winnings(hands: &[Hand], rules: Rules) -> Option<u32> {
+    let ranked_hands = rank_hands(hands, rules);
+    trace!("ranked: {ranked_hands:#?}");
+
+    let mut sum: u32 = 0;
+
+    for (i, hand) in ranked_hands.iter().enumerate() {
+        let rank = u32::try_from(i + 1).ok()?;
+        sum = sum.checked_add(rank.checked_mul(hand.bid)?)?;
+    }
+
+    Some(sum)
+}
+
+fn too_big() -> Error {
+    Error::NoSolution("the total winnings don't fit in 32 bits".to_owned())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(hands: &Self::Input) -> Result<impl Display, Error> {
+        let hands = hands.iter().map(without_jokers).collect::<Vec<_>>();
+        total_winnings(&hands, Rules::Standard).ok_or_else(too_big)
+    }
+
+    fn part2(hands: &Self::Input) -> Result<impl Display, Error> {
+        total_winnings(hands, Rules::Jokers).ok_or_else(too_big)
+    }
+}
+
+/// `count` hands of random cards, bidding up to 1000
+impl Generate for Day07 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let mut input = String::new();
+        for _ in 0..size.count {
+            let hand: String = (0..HAND_LEN)
+                .map(|_| CARDS[rng.gen_range(0..CARDS_LEN)])
+                .collect();
+            input.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A few of the cards only (jokers included), so that the hands often
+    /// repeat cards (and so have interesting kinds) or are the same altogether.
+    const FEW_CARDS: [char; 7] = ['J', '2', '3', '4', 'Q', 'K', 'A'];
+
+    fn rules() -> impl Strategy<Value = Rules> {
+        prop_oneof![Just(Rules::Standard), Just(Rules::Jokers)]
+    }
+
+    fn hand(rules: Rules) -> impl Strategy<Value = Hand> {
+        let card = prop::sample::select(&FEW_CARDS[..]);
+        (prop::array::uniform5(card), 1..=1000u32).prop_map(move |(cards, bid)| {
+            let hand = get_hand(cards, bid);
+            match rules {
+                Rules::Standard => without_jokers(&hand),
+                Rules::Jokers => hand,
+            }
+        })
+    }
+
+    fn hands() -> impl Strategy<Value = (Rules, Vec<Hand>)> {
+        rules().prop_flat_map(|rules| (Just(rules), prop::collection::vec(hand(rules), 3..=20)))
+    }
+
+    proptest! {
+        #[test]
+        fn comparison_is_a_total_order((rules, hands) in hands()) {
+            for a in &hands {
+                prop_assert_eq!(compare_hands(a, a, rules), Ordering::Equal);
+                for b in &hands {
+                    let ab = compare_hands(a, b, rules);
+                    prop_assert_eq!(ab, compare_hands(b, a, rules).reverse());
+                    for c in &hands {
+                        if ab != Ordering::Greater && compare_hands(b, c, rules) != Ordering::Greater
+                        {
+                            prop_assert_ne!(compare_hands(a, c, rules), Ordering::Greater);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn comparison_agrees_with_kinds((rules, hands) in hands()) {
+            for a in &hands {
+                for b in &hands {
+                    if a.kind != b.kind {
+                        prop_assert_eq!(compare_hands(a, b, rules), a.kind.cmp(&b.kind));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn ranking_is_sorted((rules, hands) in hands()) {
+            let ranked = rank_hands(&hands, rules);
+
+            prop_assert_eq!(ranked.len(), hands.len());
+            for pair in ranked.windows(2) {
+                prop_assert_ne!(compare_hands(&pair[0], &pair[1], rules), Ordering::Greater);
+                prop_assert!(pair[0].kind <= pair[1].kind);
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Day, Part};
+use day7::Day07;
+
+const DAY: Day = Day::of::<Day07>();
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1() {
+    assert_eq!(solve(EXAMPLE, Part::One), "6440");
+}
+
+#[test]
+fn part2() {
+    assert_eq!(solve(EXAMPLE, Part::Two), "5905");
+}
@@ -0,0 +1,499 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{
+    paragraphs, Animate, Cell, Comparison, CrossCheck, Error, GenRng, Generate, Grid, ParseError,
+    Picture, Point, Recorder, Recording, Size, Solution, Span, Step, Style,
+};
+use log::{debug, trace};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const START_NODE: &str = "AAA";
+const END_NODE: &str = "ZZZ";
+/// Steps after which [`traverse_parallel`] gives up
+const PARALLEL_STEP_LIMIT: usize = 1_000_000;
+
+type Map = HashMap<String, Directions>;
+
+#[derive(Clone, Debug)]
+pub enum Order {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Debug)]
+pub struct Directions {
+    left: String,
+    right: String,
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Order>, Map), ParseError> {
+    let tokens = paragraphs(input);
+
+    let instructions = tokens
+        .first()
+        .ok_or_else(|| Span::missing(1).error("a list of `L`/`R` instructions"))?;
+    let order = instructions
+        .iter()
+        .flat_map(|line| line.span().trim().chars())
+        .map(|(span, ch)| match ch {
+            'L' => Ok(Order::Left),
+            'R' => Ok(Order::Right),
+            _ => Err(span.error("`L` or `R`")),
+        })
+        .collect::<Result<Vec<Order>, ParseError>>()?;
+
+    let last_instruction = instructions[instructions.len() - 1].number;
+    let lines = tokens
+        .get(1)
+        .ok_or_else(|| Span::missing(last_instruction + 2).error("a list of nodes"))?;
+    if let Some(extra) = tokens.get(2) {
+        return Err(extra[0].span().error("end of the input"));
+    }
+
+    let mut map = Map::with_capacity(lines.len());
+    let mut neighbours = Vec::with_capacity(lines.len() * 2);
+
+    for line in lines {
+        let line = line.span().trim();
+        let (node_name, node_lr_all) = line
+            .split_once(" = ")
+            .ok_or_else(|| line.end().error("` = ` after the node name"))?;
+
+        let node_lr_all = node_lr_all
+            .strip_prefix("(")
+            .and_then(|lr| lr.strip_suffix(")"))
+            .ok_or_else(|| node_lr_all.error("`(<left>, <right>)`"))?;
+        let (left, right) = node_lr_all
+            .split_once(", ")
+            .ok_or_else(|| node_lr_all.error("`<left>, <right>`"))?;
+
+        let node_name = node_name.trim();
+        if map.contains_key(node_name.text) {
+            return Err(node_name.error("a node that wasn't defined before"));
+        }
+        neighbours.extend([left, right]);
+
+        map.insert(
+            node_name.text.to_owned(),
+            Directions {
+                left: left.text.to_owned(),
+                right: right.text.to_owned(),
+            },
+        );
+    }
+
+    if let Some(undefined) = neighbours.iter().find(|node| !map.contains_key(node.text)) {
+        return Err(undefined.error("a node defined in the network"));
+    }
+
+    Ok((order, map))
+}
+
+impl Order {
+    fn symbol(&self) -> char {
+        match self {
+            Order::Left => 'L',
+            Order::Right => 'R',
+        }
+    }
+}
+
+fn sorted_nodes(map: &Map) -> Vec<(&str, &Directions)> {
+    let mut nodes: Vec<_> = map
+        .iter()
+        .map(|(node, directions)| (node.as_str(), directions))
+        .collect();
+    nodes.sort_unstable_by_key(|&(node, _)| node);
+    nodes
+}
+
+/// The instructions, then the nodes one per line, sorted by their names
+fn network_picture(order: &[Order], map: &Map) -> Picture {
+    let mut lines = vec![
+        order.iter().map(Order::symbol).collect::<String>(),
+        String::new(),
+    ];
+    for (node, directions) in sorted_nodes(map) {
+        lines.push(format!(
+            "{node} = ({}, {})",
+            directions.left, directions.right
+        ));
+    }
+
+    let width = lines.iter().map(String::len).max().unwrap_or(0);
+    let rows = lines
+        .iter()
+        .map(|line| {
+            let mut cells: Vec<Cell> = line.chars().map(|ch| Cell::new(ch, Style::Plain)).collect();
+            cells.resize(width, Cell::BLANK);
+            cells
+        })
+        .collect();
+    Grid::from_rows(rows).expect("All the rows are padded to the same length")
+}
+
+/// Rows of the nodes in [`network_picture`]
+fn node_rows(map: &Map) -> HashMap<&str, usize> {
+    sorted_nodes(map)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (node, _))| (node, i + 2))
+        .collect()
+}
+
+/// Going from `from` to `to`, following the `count`-th instruction
+fn transition_step(
+    rows: &HashMap<&str, usize>,
+    order: &[Order],
+    count: usize,
+    from: &str,
+    to: &str,
+) -> Step {
+    let name = |node: &str| {
+        let i = rows[node];
+        node.chars()
+            .enumerate()
+            .map(|(j, ch)| (Point::new(i, j), ch))
+            .collect::<Vec<_>>()
+    };
+    let instruction = count % order.len();
+
+    name(from)
+        .into_iter()
+        .fold(
+            Step::new(format!("step {}: {from} -> {to}", count + 1)),
+            |step, (point, ch)| step.change(point, Cell::new(ch, Style::Visited)),
+        )
+        .focus([Point::new(0, instruction)])
+        .focus(name(to).into_iter().map(|(point, _)| point))
+}
+
+/// `None` if `ZZZ` can't be reached from `AAA`
+fn traverse(order: &[Order], map: &Map, recorder: &mut Recorder) -> Option<u32> {
+    let mut count = 0;
+    // after that many steps some (node, instruction) pair had to repeat
+    let limit = map.len() * order.len();
+
+    let mut current_node = START_NODE;
+    let rows = recorder.is_on().then(|| node_rows(map));
+    recorder.start(format!("{START_NODE} to {END_NODE}"), || {
+        network_picture(order, map)
+    });
+
+    while current_node != END_NODE {
+        if count > limit {
+            return None;
+        }
+        let current_directions = &map[current_node];
+        let current_order = &order[count % order.len()];
+        let next_node = match current_order {
+            Order::Left => &current_directions.left,
+            Order::Right => &current_directions.right,
+        };
+
+        trace!("current node: {current_node}, next node: {next_node}, order: {current_order:?}");
+        if let Some(rows) = &rows {
+            recorder.step(|| transition_step(rows, order, count, current_node, next_node));
+        }
+
+        current_node = next_node;
+
+        count += 1;
+    }
+
+    Some(count as u32)
+}
+
+fn find_all_starting(map: &Map) -> Vec<&str> {
+    let mut starting = Vec::new();
+
+    for node in map.keys() {
+        if node.ends_with('A') {
+            starting.push(node.as_str());
+        }
+    }
+
+    starting
+}
+
+fn find_all_ending(map: &Map) -> Vec<&str> {
+    let mut starting = Vec::new();
+
+    for node in map.keys() {
+        if node.ends_with('Z') {
+            starting.push(node.as_str());
+        }
+    }
+
+    starting
+}
+
+fn are_ending(nodes: &Vec<&str>) -> bool {
+    for &node in nodes {
+        if !node.ends_with('Z') {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn ending_at_least(nodes: &Vec<&str>, num: usize) -> bool {
+    let mut count = 0;
+    for &node in nodes {
+        if node.ends_with('Z') {
+            count += 1;
+        }
+    }
+
+    count >= num
+}
+
+/// Too many posibilities - can't work in reasonable time
+///
+/// Let it work for 2-3h, didn't yeld a result
+/// (even in --release mode...), so it gives up after `limit` steps
+fn traverse_parallel(order: &[Order], map: &Map, limit: usize) -> Option<usize> {
+    let mut current_nodes = find_all_starting(map);
+
+    debug!("starting points: {current_nodes:?}");
+    debug!("ending points: {:?}", find_all_ending(map));
+
+    let mut count = 0;
+
+    while !are_ending(&current_nodes) {
+        if count == limit {
+            return None;
+        }
+        let nodes_iter = current_nodes.clone();
+        for (i, &node) in nodes_iter.iter().enumerate() {
+            let current_directions = &map[node];
+
+            let current_order = &order[count % order.len()];
+            let next_node = match current_order {
+                Order::Left => &current_directions.left,
+                Order::Right => &current_directions.right,
+            };
+
+            current_nodes[i] = next_node;
+        }
+        if ending_at_least(&current_nodes, 4) {
+            debug!("count at {count}, current nodes: {current_nodes:?}");
+        }
+        count += 1;
+    }
+
+    Some(count)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    let mut a = a;
+    let mut b = b;
+
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+
+    a
+}
+
+/// `None` if it doesn't fit in a `usize`
+fn lcm(nums: &[usize]) -> Option<usize> {
+    if nums.len() == 1 {
+        return Some(nums[0]);
+    }
+
+    let a = nums[0];
+    let b = lcm(&nums[1..])?;
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// LCM
+///
+/// Only right if every start reaches its end in a cycle of the same length
+/// (which is the case for the puzzle inputs). `None` if some start never
+/// reaches an end.
+fn traverse_lcm(order: &[Order], map: &Map, recorder: &mut Recorder) -> Option<usize> {
+    let mut nodes = find_all_starting(map);
+    let mut counts = Vec::with_capacity(nodes.len());
+    // after that many steps some (node, instruction) pair had to repeat
+    let limit = map.len() * order.len();
+    let rows = recorder.is_on().then(|| node_rows(map));
+
+    for node in nodes.iter_mut() {
+        recorder.start(format!("{node} to a node ending with `Z`"), || {
+            network_picture(order, map)
+        });
+        let mut count = 0;
+        while !node.ends_with('Z') {
+            if count > limit {
+                return None;
+            }
+            let current_directions = &map[*node];
+            let current_order = &order[count % order.len()];
+            let next_node = match current_order {
+                Order::Left => &current_directions.left,
+                Order::Right => &current_directions.right,
+            };
+            if let Some(rows) = &rows {
+                recorder.step(|| transition_step(rows, order, count, node, next_node));
+            }
+
+            *node = next_node;
+            count += 1;
+        }
+        debug!("reached {node} after {count} steps");
+        counts.push(count);
+    }
+
+    lcm(&counts)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = (Vec<Order>, Map);
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((order, map): &Self::Input) -> Result<impl Display, Error> {
+        if !map.contains_key(START_NODE) {
+            return Err(Error::NoSolution(format!(
+                "there is no `{START_NODE}` node to start from"
+            )));
+        }
+
+        traverse(order, map, &mut Recorder::off()).ok_or_else(|| {
+            Error::NoSolution(format!("`{END_NODE}` can't be reached from `{START_NODE}`"))
+        })
+    }
+
+    fn part2((order, map): &Self::Input) -> Result<impl Display, Error> {
+        if find_all_starting(map).is_empty() {
+            return Err(Error::NoSolution(
+                "there are no nodes ending with `A` to start from".to_owned(),
+            ));
+        }
+
+        // bruteforce (did not work)
+        // traverse_parallel(order, map, PARALLEL_STEP_LIMIT)
+
+        // LCM (it works!)
+        traverse_lcm(order, map, &mut Recorder::off()).ok_or_else(|| {
+            Error::NoSolution("some starting node never reaches a node ending with `Z`".to_owned())
+        })
+    }
+}
+
+/// Following the instructions from `AAA`, then from every node ending with
+/// `A`, as far as they'd have to for part 2
+impl Animate for Day08 {
+    fn animate((order, map): &Self::Input) -> Result<Vec<Recording>, Error> {
+        let mut recorder = Recorder::on();
+        if map.contains_key(START_NODE) {
+            traverse(order, map, &mut recorder);
+        }
+        traverse_lcm(order, map, &mut recorder);
+
+        Ok(recorder.finish())
+    }
+}
+
+impl CrossCheck for Day08 {
+    fn cross_check((order, map): &Self::Input) -> Vec<Comparison> {
+        if find_all_starting(map).is_empty() {
+            return vec![];
+        }
+
+        vec![Comparison::new(
+            "traverse_parallel vs traverse_lcm",
+            traverse_parallel(order, map, PARALLEL_STEP_LIMIT),
+            traverse_lcm(order, map, &mut Recorder::off()),
+        )]
+    }
+}
+
+/// Name of the `n`-th node ending with `last`, e.g. for `Z`: `AAZ`, `ABZ`,
+/// ..., `ZZZ`, then `BAAZ` and so on
+fn node_name(mut n: usize, last: char) -> String {
+    let mut name = vec![last];
+    while name.len() < 3 || n > 0 {
+        name.push(char::from(b'A' + (n % 26) as u8));
+        n /= 26;
+    }
+
+    name.into_iter().rev().collect()
+}
+
+/// A network with `length` instructions for `count` ghosts, the first one
+/// going from `AAA` to `ZZZ`.
+///
+/// Every ghost walks a cycle through its end, getting there after a
+/// multiple of the instructions, just like in the puzzle, so the LCM of
+/// part 2 works. The direction a ghost doesn't take leads anywhere.
+impl Generate for Day08 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let length = size.length.max(1);
+        // one more and the last ghost would end at `ZZZ` as well
+        let ghosts = size.count.clamp(1, 26 * 26 - 1);
+        let order: Vec<Order> = (0..length)
+            .map(|_| if rng.gen() { Order::Left } else { Order::Right })
+            .collect();
+
+        // (node, where the instructions take it, in which direction)
+        let mut path = vec![];
+        let mut middle_nodes = 0;
+        for ghost in 0..ghosts {
+            let cycle = rng.gen_range(1..=6) * length;
+            let end = match ghost {
+                0 => node_name(26 * 26 - 1, 'Z'),
+                _ => node_name(ghost, 'Z'),
+            };
+
+            let mut nodes = vec![node_name(ghost, 'A')];
+            for _ in 1..cycle {
+                let last = char::from(b'B' + (middle_nodes % 24) as u8);
+                nodes.push(node_name(middle_nodes / 24, last));
+                middle_nodes += 1;
+            }
+            nodes.push(end);
+
+            for (step, node) in nodes.iter().enumerate() {
+                let next = nodes.get(step + 1).unwrap_or(&nodes[1]);
+                path.push((node.clone(), next.clone(), &order[step % length]));
+            }
+        }
+
+        let names: Vec<String> = path.iter().map(|(node, _, _)| node.clone()).collect();
+        let mut lines: Vec<String> = path
+            .into_iter()
+            .map(|(node, next, direction)| {
+                let other = names.choose(rng).expect("There is at least one node");
+                let (left, right) = match direction {
+                    Order::Left => (&next, other),
+                    Order::Right => (other, &next),
+                };
+                format!("{node} = ({left}, {right})")
+            })
+            .collect();
+        lines.shuffle(rng);
+
+        let order: String = order
+            .iter()
+            .map(|direction| match direction {
+                Order::Left => 'L',
+                Order::Right => 'R',
+            })
+            .collect();
+        format!("{order}\n\n{}\n", lines.join("\n"))
+    }
+}
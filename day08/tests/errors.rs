@@ -0,0 +1,24 @@
+use aoc_common::{Day, Error, ParseError, Part};
+use day8::Day08;
+
+const DAY: Day = Day::of::<Day08>();
+
+fn error(input: &str) -> Error {
+    DAY.solve(input, Part::One).unwrap_err()
+}
+
+#[test]
+fn positions_in_malformed_nodes() {
+    assert_eq!(
+        error("RL\n\nAAA = (BBB, BBB)\nBBB = (AAA ZZZ)\n"),
+        Error::Parse(ParseError::new(4, 8, "AAA ZZZ", "`<left>, <right>`"))
+    );
+    assert_eq!(
+        error("RL\n\nAAA = (ZZZ, ZZZ)\nZZZ (ZZZ, ZZZ)\n"),
+        Error::Parse(ParseError::new(4, 15, "", "` = ` after the node name"))
+    );
+    assert_eq!(
+        error("RL\n\nAAA = BBB, BBB\n"),
+        Error::Parse(ParseError::new(3, 7, "BBB, BBB", "`(<left>, <right>)`"))
+    );
+}
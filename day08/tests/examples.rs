@@ -0,0 +1,35 @@
+use aoc_common::input::saved_on_windows;
+use aoc_common::{Day, Part};
+use day8::Day08;
+
+const DAY: Day = Day::of::<Day08>();
+
+const EXAMPLE1: &str = include_str!("../test1.txt");
+const EXAMPLE2: &str = include_str!("../test2.txt");
+const EXAMPLE3: &str = include_str!("../test3.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1_example1() {
+    assert_eq!(solve(EXAMPLE1, Part::One), "2");
+}
+
+#[test]
+fn part1_example2() {
+    assert_eq!(solve(EXAMPLE2, Part::One), "6");
+}
+
+#[test]
+fn part2_example3() {
+    assert_eq!(solve(EXAMPLE3, Part::Two), "6");
+}
+
+#[test]
+fn windows_line_endings() {
+    assert_eq!(solve(&saved_on_windows(EXAMPLE1), Part::One), "2");
+    assert_eq!(solve(&saved_on_windows(EXAMPLE2), Part::One), "6");
+    assert_eq!(solve(&saved_on_windows(EXAMPLE3), Part::Two), "6");
+}
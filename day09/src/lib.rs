@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use aoc_common::{lines, Error, GenRng, Generate, ParseError, Size, Solution};
+use log::{debug, trace};
+use rand::Rng;
+
+type Number = i32;
+type Sequence = Vec<Number>;
+
+fn parse_input(input: &str) -> Result<Vec<Sequence>, ParseError> {
+    lines(input)
+        .filter(|line| !line.is_blank())
+        .map(|line| {
+            let seq = line
+                .span()
+                .split_whitespace()
+                .map(|num| num.parse("a number"))
+                .collect::<Result<Sequence, ParseError>>()?;
+            if seq.len() < 2 {
+                return Err(line.span().end().error("at least 2 numbers"));
+            }
+            Ok(seq)
+        })
+        .collect()
+}
+
+fn is_zeroed(vector: &Sequence) -> bool {
+    *vector == vec![0; vector.len()]
+}
+
+// All of those return `None` when a number doesn't fit in a `Number`
+
+fn calc_diff(seq: &Sequence) -> Option<Sequence> {
+    let mut diffs = vec![0; seq.len()-1];
+    for (i, window) in seq.windows(2).enumerate() {
+        diffs[i] = window[1].checked_sub(window[0])?;
+    }
+
+    Some(diffs)
+}
+
+fn next_num(seq: &Sequence) -> Option<Number> {
+    let diffs = calc_diff(seq)?;
+    trace!("line: {seq:?}, diff: {diffs:?}");
+    if is_zeroed(&diffs) || diffs.len() == 1 {
+        return seq.last().unwrap().checked_add(*diffs.last().unwrap());
+    }
+
+    seq.last().unwrap().checked_add(next_num(&diffs)?)
+}
+
+fn previous_num(seq: &Sequence) -> Option<Number> {
+    let diffs = calc_diff(seq)?;
+    trace!("line: {seq:?}, diff: {diffs:?}");
+    if is_zeroed(&diffs) || diffs.len() == 1 {
+        return seq[0].checked_sub(diffs[0]);
+    }
+
+    seq[0].checked_sub(previous_num(&diffs)?)
+}
+
+fn sum_predicate<F>(seqs: &[Sequence], predicate: &F) -> Option<Number>
+where 
+    F: Fn(&Sequence) -> Option<Number> + Clone
+{
+    let mut sum: Number = 0;
+
+    for seq in seqs {
+        let next_number = predicate(seq)?;
+        debug!("line: {seq:?}, predicted: {next_number}");
+        sum = sum.checked_add(next_number)?;
+    }
+
+    Some(sum)
+}
+
+fn too_big() -> Error {
+    Error::NoSolution("the extrapolated values don't fit in 32 bits".to_owned())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(seqs: &Self::Input) -> Result<impl Display, Error> {
+        sum_predicate(seqs, &next_num).ok_or_else(too_big)
+    }
+
+    fn part2(seqs: &Self::Input) -> Result<impl Display, Error> {
+        sum_predicate(seqs, &previous_num).ok_or_else(too_big)
+    }
+}
+
+/// `count` histories of `length` (at least 2) values. Like in the puzzle,
+/// they are polynomials (of degree up to 3), so the differences end up
+/// zeroed; the values are kept small enough for the sums not to overflow.
+impl Generate for Day09 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let length = size.length.max(2) as i64;
+        let bound = Number::MAX as i64 / 16 / (size.count as i64 + 1);
+
+        let mut input = String::new();
+        for _ in 0..size.count {
+            let mut degree = rng.gen_range(0..=3);
+            let values = loop {
+                let coefficients: Vec<i64> = (0..=degree).map(|_| rng.gen_range(-5..=5)).collect();
+                let value = |x: i64| coefficients.iter().rev().fold(0, |acc, c| acc * x + c);
+                // the extrapolated ones have to fit as well
+                let values: Vec<i64> = (-1..=length).map(value).collect();
+                if degree == 0 || values.iter().all(|value| value.abs() < bound) {
+                    break values;
+                }
+                degree -= 1;
+            };
+
+            let values: Vec<String> = values[1..values.len() - 1]
+                .iter()
+                .map(i64::to_string)
+                .collect();
+            input.push_str(&values.join(" "));
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Coefficients of a polynomial, from the constant term up.
+    fn polynomial() -> impl Strategy<Value = Vec<Number>> {
+        prop::collection::vec(-20..=20 as Number, 1..=5)
+    }
+
+    fn evaluate(coefficients: &[Number], x: Number) -> Number {
+        coefficients.iter().rev().fold(0, |value, &c| value * x + c)
+    }
+
+    proptest! {
+        /// With more values than the degree of the polynomial, the differences
+        /// bottom out and the next value is exactly the polynomial's.
+        #[test]
+        fn next_num_extrapolates_polynomials(
+            coefficients in polynomial(),
+            extra in 1..=6usize,
+        ) {
+            let len = (coefficients.len() + extra).max(2) as Number;
+            let sequence: Vec<Number> = (0..len).map(|x| evaluate(&coefficients, x)).collect();
+
+            prop_assert_eq!(next_num(&sequence), Some(evaluate(&coefficients, len)));
+        }
+    }
+}
@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{
+    lines, Animate, Cell, Comparison, CrossCheck, Direction, Error, GenRng, Generate, Grid,
+    ParseError, Picture, Point, Recorder, Recording, Render, Size, Solution, Span, Step, Style,
+};
+use log::{debug, trace};
+use rand::Rng;
+
+#[allow(unused)]
+const START_TILE: char = 'S';
+#[allow(unused)]
+const NOTHING_TILE: char = '.';
+
+// #[derive(Clone, Copy, Debug)]
+// enum Status {
+//     In,
+//     Out,
+// }
+
+/// Boards with more tiles than that could overflow the stack in
+/// [`FloodFill::Recursive`]
+const RECURSIVE_FILL_LIMIT: usize = 2_500;
+
+type Tiles = HashMap<char, HashMap<Direction, Direction>>;
+type Board = Grid<char>;
+type Mask = Grid<bool>;
+
+// All the pipe types, mapped from char to directions
+fn tiles() -> Tiles {
+    use Direction::*;
+
+    HashMap::from([
+        ('F', HashMap::from([(Right, Bottom), (Bottom, Right)])),
+        ('L', HashMap::from([(Right, Top), (Top, Right)])),
+        ('J', HashMap::from([(Left, Top), (Top, Left)])),
+        ('7', HashMap::from([(Left, Bottom), (Bottom, Left)])),
+        ('|', HashMap::from([(Top, Bottom), (Bottom, Top)])),
+        ('-', HashMap::from([(Right, Left), (Left, Right)])),
+    ])
+}
+
+fn mask_view(mask: &Mask) -> Grid<char> {
+    mask.map(|&tile| if tile { '@' } else { '.' })
+}
+
+fn parse_input(input: &str) -> Result<Board, ParseError> {
+    let board = Grid::parse(input, |tile| "|-LJ7F.S".contains(tile).then_some(tile))
+        .map_err(|err| ParseError::from_grid(err, "a pipe tile (`|-LJ7F.S`)"))?;
+
+    let mut starts = lines(input)
+        .flat_map(|line| line.span().chars())
+        .filter(|&(_, tile)| tile == START_TILE);
+    if starts.next().is_none() {
+        let end = Span::missing(lines(input).count() + 1);
+        return Err(end.error("a start tile (`S`) somewhere on the board"));
+    }
+    if let Some((span, _)) = starts.next() {
+        return Err(span.error("only one start tile"));
+    }
+
+    Ok(board)
+}
+
+fn find_start(board: &Board) -> Point {
+    board
+        .position(|&tile| tile == START_TILE)
+        .expect("Parsed boards always have a start tile")
+}
+
+fn not_a_loop() -> Error {
+    Error::NoSolution("the pipe starting at `S` is not a closed loop".to_owned())
+}
+
+fn find_loop(board: &Board, tiles: &Tiles, recorder: &mut Recorder) -> Result<Vec<Point>, Error> {
+    // find all neighbours (north, west, sout, east) of the
+    // starting tile that connected are pipes
+    // choose one, follow it's direction until you
+    // end up at `S` (start) again
+
+    let start = find_start(board);
+    debug!("starting at point {start:?}");
+    recorder.start("pipe loop", || board_picture(board));
+    let mut record = |point: Point, direction: Direction, steps: usize| {
+        recorder.step(|| {
+            Step::new(format!("step {steps}: going {direction:?}"))
+                .change(point, Cell::new(pipe_char(board[point]), Style::Loop))
+                .focus([point])
+        })
+    };
+    let (mut current_point, mut next_direction) = (start, Direction::Bottom);
+    let mut points = vec![];
+    let mut found = false;
+
+    for (point, direction) in board.neighbours4(current_point) {
+        let tile = board[point];
+        if tile == NOTHING_TILE {
+            continue;
+        }
+        if tiles[&tile].contains_key(&direction.reversed()) {
+            found = true;
+            next_direction = direction;
+            current_point = point;
+            points.push(current_point);
+            record(current_point, next_direction, points.len());
+            break;
+        }
+    }
+    if !found {
+        return Err(Error::NoSolution(
+            "did not find any pipes connected to `S`".to_owned(),
+        ));
+    }
+
+    debug!(
+        "chose to go {next_direction:?}, to point {current_point:?}, tile: `{}`",
+        board[current_point]
+    );
+
+    while current_point != start {
+        let tile = board[current_point];
+        next_direction = *tiles
+            .get(&tile)
+            .and_then(|pipe| pipe.get(&next_direction.reversed()))
+            .ok_or_else(not_a_loop)?;
+        current_point = board
+            .step(current_point, next_direction)
+            .ok_or_else(not_a_loop)?;
+        trace!("point: {current_point:?}");
+        points.push(current_point);
+        record(current_point, next_direction, points.len());
+    }
+
+    points.dedup();
+
+    Ok(points)
+}
+
+// Ray casting - doesn't really work in the 2d tiled case
+// #[allow(unused)]
+// fn is_inside_loop(start: &Point, pipe_loop: &[Point], board: &Board) -> bool {
+//     let mut min_counter = u32::MAX;
+//     for direction in Direction::ALL {
+//         let mut intersection_counter = 0u32;
+//         let mut current_point = start.clone();
+
+//         while current_point.is_inside(&Rect::from_vec(board)) {
+//             if pipe_loop.contains(&current_point) {
+//                 intersection_counter += 1;
+//             }
+//             current_point = direction.as_vec2() + current_point;
+//         }
+//         if intersection_counter < min_counter {
+//             min_counter = intersection_counter;
+//         }
+//     }
+//
+//     min_counter % 2 == 1
+// }
+
+fn unchecked_neighbours(start: Point, mask: &Mask) -> Vec<Point> {
+    mask.neighbours4(start)
+        .map(|(point, _)| point)
+        .filter(|&point| !mask[point])
+        .collect()
+}
+
+/// Works for the case of stretched map
+fn flood_fill_iterative(start: Point, mask: &mut Mask, recorder: &mut Recorder) {
+    let mut to_check = vec![start];
+
+    while let Some(current_point) = to_check.pop() {
+        let neighbours = unchecked_neighbours(current_point, mask);
+        recorder.step(|| {
+            neighbours
+                .iter()
+                .fold(fill_step(current_point), |step, &point| {
+                    step.change(point, Cell::new('.', Style::Frontier))
+                })
+        });
+        to_check.extend(neighbours);
+
+        trace!("filling {current_point:?}");
+        mask[current_point] = true;
+    }
+}
+
+fn flood_fill_recursive(start: Point, mask: &mut Mask, recorder: &mut Recorder) {
+    let neighbours = mask.neighbours4(start).collect::<Vec<_>>();
+    for (current_point, _) in neighbours {
+        if !mask[current_point] {
+            trace!("filling {current_point:?}");
+            recorder.step(|| fill_step(current_point));
+            mask[current_point] = true;
+            flood_fill_recursive(current_point, mask, recorder);
+        }
+    }
+}
+
+/// Filled tiles are outside of the loop, hence the `O`
+fn fill_step(point: Point) -> Step {
+    Step::new(format!("filling {point:?}"))
+        .change(point, Cell::new('O', Style::Visited))
+        .focus([point])
+}
+
+/// Strategy of filling the outside of the pipe loop
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloodFill {
+    Iterative,
+    /// Recurses once per filled tile, so big boards can overflow the stack
+    Recursive,
+}
+
+impl FloodFill {
+    fn run(self, start: Point, mask: &mut Mask, recorder: &mut Recorder) {
+        match self {
+            FloodFill::Iterative => flood_fill_iterative(start, mask, recorder),
+            FloodFill::Recursive => flood_fill_recursive(start, mask, recorder),
+        }
+    }
+}
+
+/// Formula: i'th point on a board is just the 2i+1'th point in the mask
+fn board_to_mask(point: Point) -> Point {
+    Point {
+        i: point.i * 2 + 1,
+        j: point.j * 2 + 1,
+    }
+}
+
+/// The other way around, for points that are on the board
+fn mask_to_board(mask_point: Point) -> Point {
+    Point {
+        i: (mask_point.i - 1) / 2,
+        j: (mask_point.j - 1) / 2,
+    }
+}
+
+fn is_board(mask_point: Point) -> bool {
+    const OFFSET: usize = 1;
+    mask_point.i % 2 == OFFSET && mask_point.j % 2 == OFFSET
+}
+
+// floodfill!!
+// NEW APPROACH: add half-coordinates!
+fn find_inside_recursive(
+    pipe_loop: &[Point],
+    board: &Board,
+    tiles: &Tiles,
+    fill: FloodFill,
+    recorder: &mut Recorder,
+) -> Vec<Point> {
+    // the plan:
+    // 1. make a bool mask 2n+1 larger in every direction
+    // 2. mark all tiles in pipe loop as true (i and j offset by +1)
+    // 3. iterate (recursively) over the tiles:
+    // starting at (0, 0):
+    // - mark the tile true (if it isn't marked itself yet)
+    // - find its neighbours and repeat for all of the neighbours
+    let mut mask = Grid::new(2 * board.rows() + 1, 2 * board.columns() + 1, false);
+
+    for (current_point, tile) in board.cells() {
+        if pipe_loop.contains(&current_point) {
+            let mask_point = board_to_mask(current_point);
+
+            mask[mask_point] = true;
+            if *tile == 'S' {
+                continue;
+            }
+
+            for &direction in tiles[tile].keys() {
+                if let Some(new_point) = mask.step(mask_point, direction) {
+                    mask[new_point] = true;
+                }
+            }
+        }
+    }
+
+    trace!("mask after marking the loop:\n{}", mask_view(&mask));
+
+    recorder.start("flood fill", || {
+        mask.map(|&tile| match tile {
+            true => Cell::new('#', Style::Loop),
+            false => Cell::new('.', Style::Empty),
+        })
+    });
+    fill.run(Point { i: 0, j: 0 }, &mut mask, recorder);
+
+    trace!("mask after the flood fill:\n{}", mask_view(&mask));
+
+    // find all points that appear on the board and are not filled
+    // formula:
+    mask.cells()
+        .filter(|&(point, &filled)| is_board(point) && !filled)
+        .map(|(point, _)| mask_to_board(point))
+        .collect()
+}
+
+/// Number of tiles enclosed by the pipe loop (part 2), filled with `fill`
+pub fn count_inside(board: &Grid<char>, fill: FloodFill) -> Result<usize, Error> {
+    let tiles: Tiles = tiles();
+    let recorder = &mut Recorder::off();
+    let pipe_loop = find_loop(board, &tiles, recorder)?;
+
+    Ok(find_inside_recursive(&pipe_loop, board, &tiles, fill, recorder).len())
+}
+
+// #[allow(unused)]
+// // Arbitrary chosen raycasting direction
+// const RAYCAST_DIRECTION: Direction = Direction::Right;
+
+// // true -> is inside, false -> is outside
+// fn cast_ray(start: &Point, pipe_loop: &[Point], board: &Board) -> bool {
+//     if pipe_loop.contains(start) {
+//         return false;
+//     }
+
+//     let mut crossings = 0;
+//     let mut current_point = start.clone();
+
+//     while current_point.is_inside(&Rect::from_vec(board)) {
+//         let current_tile = board[current_point.i as usize][current_point.j as usize];
+//         if pipe_loop.contains(&current_point) {
+//             match current_tile {
+//                 'S' | '|' | 'F' | '7' =>
+//                     crossings += 1,
+//                 _ => {},
+//             };
+//         };
+
+//         current_point = RAYCAST_DIRECTION.as_vec2() + current_point;
+//     }
+//     if crossings == 0 {
+//         return false;
+//     }
+//     crossings % 2 == 1
+// }
+
+/// Utility functions
+fn loop_view(pipe_loop: &[Point], board: &Board) -> Grid<char> {
+    let mut mask = Grid::new(board.rows(), board.columns(), false);
+    for &point in pipe_loop {
+        mask[point] = true;
+    }
+    mask_view(&mask)
+}
+
+// DID NOT WORK!
+// sadly, there seems to be a problem with casting rays - too many edgecases
+// fn find_inside(pipe_loop: &[Point], board: &Board) -> Vec<Point> {
+//     // NEW plan:
+//     // RAY CASTING! cast rays for each tile
+//     // pros: only have to cast 1 ray for each tile
+//     // cons: have to only count intersections
+//     // with tiles oriented perpendicular
+//     // ('-' for vertical and '|' for horizontal directions)
+//     // really hope it works!!!
+//     let mut points = vec![];
+
+//     for (i, row) in board.iter().enumerate() {
+//         let mut status = Status::Out;
+
+//         for (j, tile) in row.iter().enumerate() {
+//             let point = Point { i, j };
+//              if pipe_loop.contains(&point) {
+//                 match tile {
+//                     'S' | 'F' | '|' | '7' =>
+//                         status = match status {
+//                             Status::In => Status::Out,
+//                             Status::Out => Status::In,
+//                         },
+//                     _ => continue,
+//                 };
+//              } else {
+//                 match status {
+//                     Status::In => points.push(point),
+//                     Status::Out => continue,
+//                 }
+//              }
+
+//         }
+//     }
+
+//     points.dedup();
+
+//     points
+// }
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Board;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(board: &Self::Input) -> Result<impl Display, Error> {
+        let tiles: Tiles = tiles();
+        debug!("board dimensions: {} x {}", board.rows(), board.columns());
+        trace!("board:\n{board}");
+
+        let pipe_loop = find_loop(board, &tiles, &mut Recorder::off())?;
+        trace!("pipe loop:\n{}", loop_view(&pipe_loop, board));
+
+        Ok(pipe_loop.len() / 2)
+    }
+
+    fn part2(board: &Self::Input) -> Result<impl Display, Error> {
+        count_inside(board, FloodFill::Iterative)
+    }
+}
+
+/// Pipes drawn with box-drawing characters
+fn pipe_char(tile: char) -> char {
+    match tile {
+        '|' => '│',
+        '-' => '─',
+        'L' => '└',
+        'J' => '┘',
+        '7' => '┐',
+        'F' => '┌',
+        tile => tile,
+    }
+}
+
+fn board_picture(board: &Board) -> Picture {
+    board.map(|&tile| match tile {
+        NOTHING_TILE => Cell::new(tile, Style::Empty),
+        tile => Cell::new(pipe_char(tile), Style::Plain),
+    })
+}
+
+/// The loop and the tiles it encloses (as `I`s)
+impl Render for Day10 {
+    fn render(board: &Self::Input) -> Result<Picture, Error> {
+        let tiles: Tiles = tiles();
+        let recorder = &mut Recorder::off();
+        let pipe_loop = find_loop(board, &tiles, recorder)?;
+        let inside =
+            find_inside_recursive(&pipe_loop, board, &tiles, FloodFill::Iterative, recorder);
+
+        let mut picture = board_picture(board);
+        for point in pipe_loop {
+            picture[point].style = Style::Loop;
+        }
+        for point in inside {
+            picture[point] = Cell::new('I', Style::Inside);
+        }
+
+        Ok(picture)
+    }
+}
+
+/// Following the loop, then flood filling the outside of it (on the board
+/// with the half-coordinates)
+impl Animate for Day10 {
+    fn animate(board: &Self::Input) -> Result<Vec<Recording>, Error> {
+        let tiles: Tiles = tiles();
+        let mut recorder = Recorder::on();
+        let pipe_loop = find_loop(board, &tiles, &mut recorder)?;
+        find_inside_recursive(
+            &pipe_loop,
+            board,
+            &tiles,
+            FloodFill::Iterative,
+            &mut recorder,
+        );
+
+        Ok(recorder.finish())
+    }
+}
+
+impl CrossCheck for Day10 {
+    fn cross_check(board: &Self::Input) -> Vec<Comparison> {
+        let recursive = (board.rows() * board.columns() <= RECURSIVE_FILL_LIMIT)
+            .then(|| count_inside(board, FloodFill::Recursive).ok())
+            .flatten();
+
+        vec![Comparison::new(
+            "recursive vs iterative flood fill",
+            recursive,
+            count_inside(board, FloodFill::Iterative).ok(),
+        )]
+    }
+}
+
+/// The 8 neighbours of a tile, going around it clockwise
+const RING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Whether adding `point` to the blob keeps its outline a single loop: the
+/// neighbours already in the blob have to be in one piece around it (or
+/// the blob would get a hole) and one of them can't touch it only by
+/// a corner (or the outline would touch itself there)
+fn keeps_outline(blob: &Grid<bool>, point: Point) -> bool {
+    let ring: Vec<bool> = RING
+        .iter()
+        .map(|&(di, dj)| point.offset(di, dj).and_then(|p| blob.get(p)) == Some(&true))
+        .collect();
+    let pieces = (0..ring.len())
+        .filter(|&k| ring[k] && !ring[(k + ring.len() - 1) % ring.len()])
+        .count();
+    let touches_a_side = ring.iter().skip(1).step_by(2).any(|&inside| inside);
+
+    pieces == 1 && touches_a_side
+}
+
+/// A random blob of blocks without holes, grown one block at a time
+fn grow_blob(rng: &mut GenRng, rows: usize, columns: usize) -> Grid<bool> {
+    let mut blob = Grid::new(rows, columns, false);
+    blob[Point::new(rng.gen_range(0..rows), rng.gen_range(0..columns))] = true;
+
+    let target = rng.gen_range(1..=(rows * columns * 2 / 3).max(1));
+    let mut blocks = 1;
+    for _ in 0..20 * rows * columns {
+        if blocks >= target {
+            break;
+        }
+        let point = Point::new(rng.gen_range(0..rows), rng.gen_range(0..columns));
+        if !blob[point] && keeps_outline(&blob, point) {
+            blob[point] = true;
+            blocks += 1;
+        }
+    }
+
+    blob
+}
+
+/// The tile connecting the two directions
+fn pipe(directions: &[Direction]) -> char {
+    tiles()
+        .into_iter()
+        .find(|(_, connects)| directions.iter().all(|side| connects.contains_key(side)))
+        .map(|(tile, _)| tile)
+        .expect("Every two directions are connected by some pipe")
+}
+
+/// A board of `count` rows and `length` columns (at least 3) with a single
+/// closed loop: the outline of a random blob, drawn on a grid twice as
+/// dense (so it never runs next to itself) and surrounded by junk pipes.
+impl Generate for Day10 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let (rows, columns) = (size.count.max(3), size.length.max(3));
+        let blob = grow_blob(rng, (rows - 1) / 2, (columns - 1) / 2);
+
+        // block (a, b) of the blob has its corners at tiles (2a, 2b) to
+        // (2a + 2, 2b + 2); sides that don't touch another block are the loop
+        let mut on_loop = Grid::new(rows, columns, false);
+        for (block, _) in blob.cells().filter(|&(_, &inside)| inside) {
+            let center = Point::new(2 * block.i + 1, 2 * block.j + 1);
+            for direction in Direction::ALL {
+                let outside = block.step(direction).and_then(|p| blob.get(p)) != Some(&true);
+                if !outside {
+                    continue;
+                }
+                let side = center.step(direction).expect("Blocks have all their sides");
+                on_loop[side] = true;
+                for end in [direction.turned_left(), direction.turned_right()] {
+                    on_loop[side.step(end).expect("Blocks have all their corners")] = true;
+                }
+            }
+        }
+
+        let mut board = Grid::new(rows, columns, NOTHING_TILE);
+        let mut pipes = vec![];
+        for (point, _) in on_loop.cells().filter(|&(_, &inside)| inside) {
+            let directions: Vec<Direction> = on_loop
+                .neighbours4(point)
+                .filter(|&(neighbour, _)| on_loop[neighbour])
+                .map(|(_, direction)| direction)
+                .collect();
+            board[point] = pipe(&directions);
+            pipes.push(point);
+        }
+        let start = pipes[rng.gen_range(0..pipes.len())];
+        board[start] = START_TILE;
+
+        // junk can't look connected to the start, or it could be mistaken for the loop
+        let mut junk: Vec<char> = tiles().into_keys().collect();
+        junk.sort_unstable();
+        for (point, _) in on_loop.cells().filter(|&(_, &inside)| !inside) {
+            if point.manhattan(start) > 1 && rng.gen() {
+                board[point] = junk[rng.gen_range(0..junk.len())];
+            }
+        }
+
+        board
+            .iter_rows()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
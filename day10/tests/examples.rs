@@ -0,0 +1,33 @@
+use aoc_common::{Day, Part};
+use day10::Day10;
+
+const DAY: Day = Day::of::<Day10>();
+
+const EXAMPLE1: &str = include_str!("../test1.txt");
+const EXAMPLE2: &str = include_str!("../test2.txt");
+const EXAMPLE3: &str = include_str!("../test3.txt");
+const EXAMPLE4: &str = include_str!("../test4.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1_example1() {
+    assert_eq!(solve(EXAMPLE1, Part::One), "8");
+}
+
+#[test]
+fn part2_example2() {
+    assert_eq!(solve(EXAMPLE2, Part::Two), "10");
+}
+
+#[test]
+fn part2_example3() {
+    assert_eq!(solve(EXAMPLE3, Part::Two), "4");
+}
+
+#[test]
+fn part2_example4() {
+    assert_eq!(solve(EXAMPLE4, Part::Two), "8");
+}
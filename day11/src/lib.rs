@@ -0,0 +1,259 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use std::fmt::Display;
+
+use aoc_common::{
+    Cell, Error, GenRng, Generate, Grid, ParseError, Picture, Point, Render, Size, Solution, Style,
+};
+use log::{debug, trace};
+use rand::Rng;
+
+type Board = Grid<char>;
+type Pairs = HashSet<(Point, Point)>;
+
+fn parse_input(input: &str) -> Result<Board, ParseError> {
+    Grid::parse(input, |tile| matches!(tile, '.' | '#').then_some(tile))
+        .map_err(|err| ParseError::from_grid(err, "`#` or `.`"))
+}
+
+fn is_empty<'a>(slice: impl IntoIterator<Item = &'a char>) -> bool {
+    for &tile in slice {
+        if tile != '.' {
+            return false;
+        }
+    }
+    true
+}
+
+fn expand(board: &mut Board) {
+    let mut rows = vec![];
+    for row in board.iter_rows() {
+        rows.push(row.to_vec());
+        if is_empty(row) {
+            rows.push(row.to_vec());
+        }
+    }
+
+    let should_expand_cols = get_should_expand_cols(board);
+    for row in rows.iter_mut() {
+        for &j in should_expand_cols.iter().rev() {
+            row.insert(j, '.');
+        }
+    }
+
+    *board = Grid::from_rows(rows).expect("Expanded rows are all of the same length");
+}
+
+fn get_galaxies(board: &Board) -> Vec<Point> {
+    let mut galaxies = vec![];
+    for (point, &cell) in board.cells() {
+        if cell == '#' {
+            galaxies.push(point)
+        }
+    }
+
+    galaxies
+}
+
+fn pairs(board: &Board) -> Pairs {
+    let mut pairs = HashSet::new();
+    let galaxies = get_galaxies(board);
+
+    for &galaxy1 in galaxies.iter() {
+        for &galaxy2 in galaxies.iter()  {
+            if !(pairs.contains(&(galaxy1, galaxy2)) || pairs.contains(&(galaxy2, galaxy1))) {
+                pairs.insert((galaxy1, galaxy2));
+            }
+        }
+    }
+
+    pairs
+}
+
+fn shortest_path(pair: &(Point, Point)) -> usize {
+    let (a, b) = pair;
+
+    a.manhattan(*b)
+}
+
+fn sum_shortest_paths(board: &Board) -> usize {
+    let mut sum = 0;
+    let pairs = pairs(board);
+
+    for pair in pairs.iter() {
+        sum += shortest_path(pair);
+    }
+
+    sum
+}
+
+fn get_should_expand_rows(board: &Board) -> Vec<usize> {
+    let mut should_expand_rows = vec![];
+
+    for (i, row) in board.iter_rows().enumerate() {
+        if is_empty(row) {
+            should_expand_rows.push(i);
+        }
+    }
+
+    should_expand_rows
+}
+
+fn get_should_expand_cols(board: &Board) -> Vec<usize> {
+    let mut should_expand_cols = vec![];
+
+    for j in 0..board.columns() {
+        if is_empty(board.column(j)) {
+            should_expand_cols.push(j);
+        }
+    }
+
+    should_expand_cols
+}
+
+fn abs_range(a: usize, b: usize) -> Range<usize> {
+    if a > b {
+        return (b+1)..a;
+    }
+    (a+1)..b
+}
+
+fn sum_shortest_paths_bigger_expansion(board: &Board, multiplier: usize) -> usize {
+    let mut sum = 0;
+
+    let should_expand_rows = get_should_expand_rows(board);
+    let should_expand_cols = get_should_expand_cols(board);
+    let pairs = pairs(board);
+
+    for (a, b) in pairs.iter() {
+        let path = shortest_path(&(*a, *b));
+        let di = abs_range(a.i, b.i);
+        let dj = abs_range(a.j, b.j);
+        let rows = should_expand_rows.iter()
+            .filter(|&i| di.contains(i))
+            .count();
+        let cols = should_expand_cols.iter()
+            .filter(|&j| dj.contains(j))
+            .count();
+        sum += path + cols * (multiplier - 1) + rows * (multiplier - 1);
+    }
+
+    sum
+}
+
+const MULTIPLIER: usize = 1_000_000;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Board;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(board: &Self::Input) -> Result<impl Display, Error> {
+        let mut board = board.clone();
+
+        debug!(
+            "board before expansion ({}x{})",
+            board.rows(),
+            board.columns()
+        );
+        trace!("\n{board}");
+
+        expand(&mut board);
+
+        debug!(
+            "board after expansion ({}x{})",
+            board.rows(),
+            board.columns()
+        );
+        trace!("\n{board}");
+
+        Ok(sum_shortest_paths(&board))
+    }
+
+    fn part2(board: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_shortest_paths_bigger_expansion(board, MULTIPLIER))
+    }
+}
+
+/// Galaxies, and the empty rows and columns that get expanded
+impl Render for Day11 {
+    fn render(board: &Self::Input) -> Result<Picture, Error> {
+        let should_expand_rows = get_should_expand_rows(board);
+        let should_expand_cols = get_should_expand_cols(board);
+
+        let mut picture = board.map(|&tile| Cell::new(tile, Style::Empty));
+        for (point, &tile) in board.cells() {
+            if tile == '#' {
+                picture[point].style = Style::Galaxy;
+            } else if should_expand_rows.contains(&point.i) || should_expand_cols.contains(&point.j)
+            {
+                picture[point].style = Style::Expanded;
+            }
+        }
+
+        Ok(picture)
+    }
+}
+
+/// An image of `count` rows and `length` columns, one in 20 of the tiles a
+/// galaxy
+impl Generate for Day11 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let mut input = String::new();
+        for _ in 0..size.count {
+            let row: String = (0..size.length)
+                .map(|_| if rng.gen_ratio(1, 20) { '#' } else { '.' })
+                .collect();
+            input.push_str(&row);
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Small boards, mostly empty space so that some rows and columns expand.
+    fn board() -> impl Strategy<Value = Board> {
+        (1..=12usize, 1..=12usize).prop_flat_map(|(rows, columns)| {
+            prop::collection::vec(prop::bool::weighted(0.15), rows * columns).prop_map(
+                move |tiles| {
+                    let rows = tiles
+                        .chunks(columns)
+                        .map(|row| {
+                            row.iter()
+                                .map(|&galaxy| if galaxy { '#' } else { '.' })
+                                .collect()
+                        })
+                        .collect();
+                    Grid::from_rows(rows).unwrap()
+                },
+            )
+        })
+    }
+
+    proptest! {
+        /// Doubling the empty space is what `expand` does to the board.
+        #[test]
+        fn doubling_matches_expanded_board(board in board()) {
+            let mut expanded = board.clone();
+            expand(&mut expanded);
+
+            prop_assert_eq!(
+                sum_shortest_paths_bigger_expansion(&board, 2),
+                sum_shortest_paths(&expanded)
+            );
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Day, Part};
+use day11::Day11;
+
+const DAY: Day = Day::of::<Day11>();
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1() {
+    assert_eq!(solve(EXAMPLE, Part::One), "374");
+}
+
+#[test]
+fn part2() {
+    assert_eq!(solve(EXAMPLE, Part::Two), "82000210");
+}
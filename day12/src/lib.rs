@@ -0,0 +1,307 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{
+    lines, Comparison, CrossCheck, Error, GenRng, Generate, Line, ParseError, Size, Solution,
+};
+use log::{debug, trace};
+use rand::Rng;
+
+const SPRINGS_OPTIONS: [char; 2] = ['.', '#'];
+const TIMES: usize = 5;
+/// Rows with more `?`s than that take too long to brute force
+const BRUTE_FORCE_LIMIT: usize = 16;
+
+#[derive(Clone, Debug)]
+pub struct Row {
+    row: String,
+    values: Vec<u8>,
+}
+
+impl Row {
+    fn row_chars(&self) -> Vec<char> {
+        self.row.chars().collect()
+    }
+}
+
+fn parse_row(line: Line) -> Result<Row, ParseError> {
+    let mut parts = line.span().split_whitespace();
+    let springs = parts
+        .next()
+        .ok_or_else(|| line.span().error("a row of springs"))?;
+    if let Some((span, _)) = springs.chars().find(|&(_, ch)| !"?.#".contains(ch)) {
+        return Err(span.error("`?`, `.` or `#`"));
+    }
+
+    let values = parts
+        .next()
+        .ok_or_else(|| springs.end().error("a list of group sizes"))?
+        .split(',')
+        .map(|value| value.parse("a group size"))
+        .collect::<Result<_, _>>()?;
+    if let Some(extra) = parts.next() {
+        return Err(extra.error("the end of the line"));
+    }
+
+    Ok(Row {
+        row: String::from(springs.text),
+        values,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Row>, ParseError> {
+    lines(input)
+        .filter(|line| !line.is_blank())
+        .map(parse_row)
+        .collect()
+}
+
+fn check_row(row: &str, values: &Vec<u8>) -> bool {
+    let mut is_accumulating = false;
+    let mut count = 0;
+    let mut nums = vec![];
+
+    for ch in row.chars() {
+        if ch == '#' {
+            count += 1;
+            is_accumulating = true;
+        } else {
+            if is_accumulating {
+                nums.push(count);
+                is_accumulating = !is_accumulating;
+            }
+            count = 0;
+        }
+    }
+    if count != 0 {
+        nums.push(count);
+    }
+
+    trace!("{row}: nums: {nums:?}, values: {values:?}");
+    nums == *values
+}
+
+fn get_combinations_magic(row_len: usize) -> Vec<String> {
+    // starting from the single empty combination, so rows with none or
+    // just one `?` get 1 or 2 combinations, not the 4 two-char ones
+    (0..row_len).fold(vec![String::new()], |acc: Vec<String>, _| {
+        acc.into_iter()
+            .cartesian_product(SPRINGS_OPTIONS.iter()) // iterative cartesian of cartesians!
+            .map(|(a, b)| format!("{}{}", a, b))
+            .collect()
+    })
+}
+
+fn count_question_marks(row: &Row) -> usize {
+    row.row.chars().filter(|e| *e == '?').count()
+}
+
+fn insert_combination(row: &Row, combination: &str) -> String {
+    let mut accum = String::from("");
+    let mut count = 0;
+    let combination: Vec<char> = combination.chars().collect();
+
+    for ch in row.row.chars() {
+        if ch == '?' {
+            accum.push(combination[count]);
+            count += 1;
+        } else {
+            accum.push(ch);
+        }
+    }
+
+    accum
+}
+
+/// Brute force: tries every way of filling in the `?`s
+pub fn num_of_combinations(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|row| {
+            get_combinations_magic(count_question_marks(row))
+                .iter()
+                .filter(|&combination| {
+                    check_row(&insert_combination(row, combination), &row.values)
+                })
+                .count()
+        })
+        .sum()
+}
+
+fn repeat_with_separator(string: &str, times: usize, sep: &str) -> String {
+    let mut accum = String::new();
+
+    for i in 0..times {
+        accum.push_str(string);
+        if i != times - 1 {
+            accum.push_str(sep);
+        }
+    }
+
+    accum
+}
+
+fn unfold_springs(rows: &[Row], times: usize) -> Vec<Row> {
+    let mut unfolded_rows = vec![];
+
+    for row in rows {
+        unfolded_rows.push(Row {
+            row: repeat_with_separator(&row.row, times, "?"),
+            values: row.values.repeat(times),
+        })
+    }
+
+    unfolded_rows
+}
+
+fn get_row_combinations_dynamic(
+    row: &Vec<char>,
+    blocks: &Vec<u8>,
+    row_i: usize,
+    blocks_i: usize,
+    current_block: usize,
+    cache: &mut HashMap<(usize, usize, usize), usize>,
+) -> Option<usize> {
+    let cache_key = (row_i, blocks_i, current_block);
+    if let Some(&cache_entry) = cache.get(&cache_key) {
+        return Some(cache_entry);
+    }
+
+    if row_i == row.len() {
+        if blocks_i == blocks.len() && current_block == 0
+            || blocks_i == blocks.len() - 1 && blocks[blocks_i] as usize == current_block
+        {
+            return Some(1);
+        } else {
+            return Some(0);
+        }
+    }
+
+    let mut partial_sum: usize = 0;
+
+    for c in SPRINGS_OPTIONS {
+        if row[row_i] == c || row[row_i] == '?' {
+            let combinations = if c == '.' && current_block == 0 {
+                get_row_combinations_dynamic(row, blocks, row_i + 1, blocks_i, 0, cache)?
+            } else if c == '.'
+                && current_block > 0
+                && blocks_i < blocks.len()
+                && blocks[blocks_i] as usize == current_block
+            {
+                get_row_combinations_dynamic(row, blocks, row_i + 1, blocks_i + 1, 0, cache)?
+            } else if c == '#' {
+                get_row_combinations_dynamic(
+                    row,
+                    blocks,
+                    row_i + 1,
+                    blocks_i,
+                    current_block + 1,
+                    cache,
+                )?
+            } else {
+                0
+            };
+            partial_sum = partial_sum.checked_add(combinations)?;
+        }
+    }
+
+    cache.insert(cache_key, partial_sum);
+
+    Some(partial_sum)
+}
+
+/// `None` if the number of arrangements doesn't fit in a `usize`
+pub fn num_of_combinations_dynamic(rows: &[Row]) -> Option<usize> {
+    let mut dp = HashMap::new();
+    let mut sum: usize = 0;
+
+    for (i, row) in rows.iter().enumerate() {
+        let score = get_row_combinations_dynamic(&row.row_chars(), &row.values, 0, 0, 0, &mut dp)?;
+        debug!(
+            "row {i}: {} {:?} has {score} arrangements",
+            row.row, row.values
+        );
+        sum = sum.checked_add(score)?;
+        dp.clear();
+    }
+
+    Some(sum)
+}
+
+fn too_many() -> Error {
+    Error::NoSolution("the number of arrangements doesn't fit in 64 bits".to_owned())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(rows: &Self::Input) -> Result<impl Display, Error> {
+        num_of_combinations_dynamic(rows).ok_or_else(too_many)
+    }
+
+    fn part2(rows: &Self::Input) -> Result<impl Display, Error> {
+        let unfolded_rows = unfold_springs(rows, TIMES);
+        num_of_combinations_dynamic(&unfolded_rows).ok_or_else(too_many)
+    }
+}
+
+impl CrossCheck for Day12 {
+    fn cross_check(rows: &Self::Input) -> Vec<Comparison> {
+        let naive = rows
+            .iter()
+            .all(|row| count_question_marks(row) <= BRUTE_FORCE_LIMIT)
+            .then(|| num_of_combinations(rows));
+
+        vec![Comparison::new(
+            "num_of_combinations vs num_of_combinations_dynamic",
+            naive,
+            num_of_combinations_dynamic(rows),
+        )]
+    }
+}
+
+/// `count` rows of `length` springs, about a third of them unknown.
+/// The groups come from a random arrangement, so every row has at least one
+/// damaged group and at least one arrangement.
+impl Generate for Day12 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let length = size.length.max(1);
+        let max_group = u8::MAX as usize;
+
+        let mut input = String::new();
+        for _ in 0..size.count {
+            let mut springs: Vec<char> = (0..length)
+                .map(|_| SPRINGS_OPTIONS[rng.gen_range(0..SPRINGS_OPTIONS.len())])
+                .collect();
+            // the group sizes have to fit in a `u8`
+            for i in (max_group..length).step_by(max_group + 1) {
+                springs[i] = '.';
+            }
+            if !springs.contains(&'#') {
+                springs[0] = '#';
+            }
+
+            let groups: Vec<String> = springs
+                .split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect();
+            let row: String = springs
+                .iter()
+                .map(|&spring| if rng.gen_ratio(1, 3) { '?' } else { spring })
+                .collect();
+
+            input.push_str(&format!("{row} {}\n", groups.join(",")));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::{Day, Part};
+use day12::Day12;
+
+const DAY: Day = Day::of::<Day12>();
+
+const EXAMPLE: &str = include_str!("../test.txt");
+
+fn solve(input: &str, part: Part) -> String {
+    DAY.solve(input, part).unwrap()
+}
+
+#[test]
+fn part1() {
+    assert_eq!(solve(EXAMPLE, Part::One), "21");
+}
+
+#[test]
+fn part2() {
+    assert_eq!(solve(EXAMPLE, Part::Two), "525152");
+}
+
+#[test]
+fn arrangements_per_row() {
+    let part1 = ["1", "4", "1", "1", "4", "10"];
+    let part2 = ["1", "16384", "1", "16", "2500", "506250"];
+
+    for ((row, part1), part2) in EXAMPLE.lines().zip(part1).zip(part2) {
+        assert_eq!(solve(row, Part::One), part1, "part 1 of `{row}`");
+        assert_eq!(solve(row, Part::Two), part2, "part 2 of `{row}`");
+    }
+}
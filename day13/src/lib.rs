@@ -0,0 +1,569 @@
+use std::fmt::{self, Display};
+
+use aoc_common::{
+    paragraphs, Animate, Cell, Error, GenRng, Generate, Grid, ParseError, Picture, Point, Recorder,
+    Recording, Render, Size, Solution, Step, Style,
+};
+use log::{debug, trace};
+use rand::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tile {
+    Ash,
+    Rock,
+}
+
+impl Tile {
+    fn inverse(&self) -> Self {
+        match self {
+            Self::Ash => Self::Rock,
+            Self::Rock => Self::Ash,
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Tile::Ash => '.',
+            Tile::Rock => '#',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+type Board = Grid<Tile>;
+type Sum = usize;
+
+const ROW_MULTIPLIER: Sum = 100;
+
+fn parse_input(input: &str) -> Result<Vec<Board>, ParseError> {
+    paragraphs(input)
+        .into_iter()
+        .map(|board| {
+            Grid::parse_lines(board, |ch| match ch {
+                '#' => Some(Tile::Rock),
+                '.' => Some(Tile::Ash),
+                _ => None,
+            })
+            .map_err(|err| ParseError::from_grid(err, "`#` or `.`"))
+        })
+        .collect()
+}
+
+/// Tiles of the `n`-th row (or column)
+fn line(board: &Board, is_row: bool, n: usize) -> Vec<Point> {
+    if is_row {
+        (0..board.columns()).map(|j| Point::new(n, j)).collect()
+    } else {
+        (0..board.rows()).map(|i| Point::new(i, n)).collect()
+    }
+}
+
+fn comparison_step(board: &Board, is_row: bool, (p, q): (i32, i32), same: bool) -> Step {
+    let lines = if is_row { "rows" } else { "columns" };
+    let verdict = if same { "match" } else { "differ" };
+    Step::new(format!("{lines} {p} and {q} {verdict}"))
+        .focus(line(board, is_row, p as usize))
+        .focus(line(board, is_row, q as usize))
+}
+
+fn found_step(board: &Board, is_row: bool, (a, b): (usize, usize)) -> Step {
+    let lines = if is_row { "rows" } else { "columns" };
+    line(board, is_row, a)
+        .into_iter()
+        .chain(line(board, is_row, b))
+        .fold(
+            Step::new(format!("reflects between {lines} {a} and {b}")),
+            |step, point| step.change(point, Cell::new(board[point].symbol(), Style::Reflection)),
+        )
+}
+
+fn find_row_axis(board: &Board, recorder: &mut Recorder) -> Option<(usize, usize)> {
+    for i in 0..(board.rows() - 1) {
+        let (mut p, mut q) = (i as i32, i as i32 + 1);
+        loop {
+            let same = board.row(p as usize) == board.row(q as usize);
+            recorder.step(|| comparison_step(board, true, (p, q), same));
+            if !same {
+                break;
+            }
+            p -= 1;
+            q += 1;
+
+            if p < 0 || q >= board.rows() as i32 {
+                recorder.step(|| found_step(board, true, (i, i + 1)));
+                return Some((i, i + 1));
+            }
+        }
+    }
+
+    None
+}
+
+fn find_col_axis(board: &Board, recorder: &mut Recorder) -> Option<(usize, usize)> {
+    for j in 0..(board.columns() - 1) {
+        let (mut p, mut q) = (j as i32, j as i32 + 1);
+        loop {
+            let same = board.column(p as usize).eq(board.column(q as usize));
+            recorder.step(|| comparison_step(board, false, (p, q), same));
+            if !same {
+                break;
+            }
+            p -= 1;
+            q += 1;
+
+            if p < 0 || q >= board.columns() as i32 {
+                recorder.step(|| found_step(board, false, (j, j + 1)));
+                return Some((j, j + 1));
+            }
+        }
+    }
+
+    None
+}
+
+fn sum_for_board(board: &Board, recorder: &mut Recorder) -> Sum {
+    let mut sum = 0;
+    trace!("board:\n{board}");
+    if let Some((top, bottom)) = find_row_axis(board, recorder) {
+        debug!("found row axis: {:?}", (top, bottom));
+        sum += (top + 1) * ROW_MULTIPLIER;
+    }
+    if let Some((left, right)) = find_col_axis(board, recorder) {
+        debug!("found column axis: {:?}", (left, right));
+        sum += left + 1;
+    }
+
+    sum
+}
+
+fn sum_for_boards(boards: &[Board]) -> Sum {
+    boards
+        .iter()
+        .map(|board| sum_for_board(board, &mut Recorder::off()))
+        .sum()
+}
+
+fn find_diffs<'a>(
+    row_a: impl IntoIterator<Item = &'a Tile>,
+    row_b: impl IntoIterator<Item = &'a Tile>,
+) -> Vec<usize> {
+    let mut accum = vec![];
+    for (i, (tile_a, tile_b)) in row_a.into_iter().zip(row_b).enumerate() {
+        if tile_a != tile_b {
+            accum.push(i);
+        }
+    }
+    accum
+}
+
+fn find_row_smudge(
+    board: &Board,
+    previous_axis: (usize, usize),
+    is_row: bool,
+) -> Option<(usize, usize)> {
+    trace!("finding row axis for board:\n{board}");
+    let (axis_i, axis_j);
+    if is_row {
+        (axis_i, axis_j) = previous_axis;
+    } else {
+        (axis_i, axis_j) = (board.rows() + 1, board.rows() + 1);
+    }
+    for i in 0..(board.rows() - 1) {
+        // the old axis can't be the new one
+        if (i, i + 1) == (axis_i, axis_j) {
+            continue;
+        }
+        let (mut p, mut q) = (i as i32, i as i32 + 1);
+        let mut diffs = vec![];
+
+        while diffs.len() <= 1 && p >= 0 && q < board.rows() as i32 {
+            diffs.extend(find_diffs(board.row(p as usize), board.row(q as usize)));
+            trace!("diffs (extended) for p={p}, q={q}: {diffs:?}");
+            p -= 1;
+            q += 1;
+
+            if (p < 0 || q >= board.rows() as i32) && diffs.len() == 1 {
+                debug!("found new row axis: ({}, {})", i, i + 1);
+                return Some((i, i + 1));
+            }
+        }
+    }
+    None
+}
+
+fn find_col_smudge(
+    board: &Board,
+    previous_axis: (usize, usize),
+    is_column: bool,
+) -> Option<(usize, usize)> {
+    trace!("finding column axis for board:\n{board}");
+
+    let (axis_i, axis_j);
+    if is_column {
+        (axis_i, axis_j) = previous_axis;
+    } else {
+        (axis_i, axis_j) = (board.columns() + 1, board.columns() + 1);
+    }
+
+    for j in 0..(board.columns() - 1) {
+        // the old axis can't be the new one
+        if (j, j + 1) == (axis_i, axis_j) {
+            continue;
+        }
+        let (mut p, mut q) = (j as i32, j as i32 + 1);
+        let mut diffs = vec![];
+
+        while diffs.len() <= 1 && p >= 0 && q < board.columns() as i32 {
+            diffs.extend(find_diffs(
+                board.column(p as usize),
+                board.column(q as usize),
+            ));
+            trace!("diffs (extended) for p={p}, q={q}: {diffs:?}");
+            p -= 1;
+            q += 1;
+
+            if (p < 0 || q >= board.columns() as i32) && diffs.len() == 1 {
+                debug!("found new column axis: ({}, {})", j, j + 1);
+                return Some((j, j + 1));
+            }
+        }
+    }
+    None
+}
+
+fn sum_for_board_corrected(board: &Board) -> Result<Sum, Error> {
+    if let Some(axis) = find_row_axis(board, &mut Recorder::off()) {
+        if let Some((top, _)) = find_row_smudge(board, axis, true) {
+            return Ok((top + 1) * ROW_MULTIPLIER);
+        }
+        if let Some((left, _)) = find_col_smudge(board, axis, false) {
+            return Ok(left + 1);
+        }
+    }
+    if let Some(axis) = find_col_axis(board, &mut Recorder::off()) {
+        if let Some((top, _)) = find_row_smudge(board, axis, false) {
+            return Ok((top + 1) * ROW_MULTIPLIER);
+        }
+        if let Some((left, _)) = find_col_smudge(board, axis, true) {
+            return Ok(left + 1);
+        }
+    }
+
+    Err(Error::NoSolution(
+        "every corrected board should have at least 1 axis".to_owned(),
+    ))
+}
+
+fn sum_for_boards_corrected(boards: &[Board]) -> Result<Sum, Error> {
+    boards.iter().map(sum_for_board_corrected).sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Board>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(boards: &Self::Input) -> Result<impl Display, Error> {
+        Ok(sum_for_boards(boards))
+    }
+
+    fn part2(boards: &Self::Input) -> Result<impl Display, Error> {
+        sum_for_boards_corrected(boards)
+    }
+}
+
+/// A line of reflection: `Row(n)` has `n` rows above it, `Column(n)` has
+/// `n` columns to the left
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Axis {
+    Row(usize),
+    Column(usize),
+}
+
+impl Axis {
+    fn all(rows: usize, columns: usize) -> Vec<Axis> {
+        (1..rows)
+            .map(Axis::Row)
+            .chain((1..columns).map(Axis::Column))
+            .collect()
+    }
+
+    /// Pairs of tiles that are each other's reflections
+    fn mirrored(self, rows: usize, columns: usize) -> Vec<(Point, Point)> {
+        let mut pairs = vec![];
+        match self {
+            Axis::Row(n) => {
+                for k in 0..n.min(rows - n) {
+                    for j in 0..columns {
+                        pairs.push((Point::new(n - 1 - k, j), Point::new(n + k, j)));
+                    }
+                }
+            }
+            Axis::Column(n) => {
+                for k in 0..n.min(columns - n) {
+                    for i in 0..rows {
+                        pairs.push((Point::new(i, n - 1 - k), Point::new(i, n + k)));
+                    }
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Whether the tile is in one of the two lines right next to the axis
+    fn beside(self, point: Point) -> bool {
+        match self {
+            Axis::Row(n) => point.i + 1 == n || point.i == n,
+            Axis::Column(n) => point.j + 1 == n || point.j == n,
+        }
+    }
+}
+
+fn differences(board: &Board, axis: Axis) -> usize {
+    axis.mirrored(board.rows(), board.columns())
+        .into_iter()
+        .filter(|&(a, b)| board[a] != board[b])
+        .count()
+}
+
+/// Comparing the rows, then the columns of every pattern, looking for the
+/// (part 1) line of reflection
+impl Animate for Day13 {
+    fn animate(boards: &Self::Input) -> Result<Vec<Recording>, Error> {
+        let mut recorder = Recorder::on();
+        for (n, board) in boards.iter().enumerate() {
+            recorder.start(format!("pattern {}", n + 1), || {
+                board.map(|tile| match tile {
+                    Tile::Ash => Cell::new(tile.symbol(), Style::Empty),
+                    Tile::Rock => Cell::new(tile.symbol(), Style::Plain),
+                })
+            });
+            sum_for_board(board, &mut recorder);
+        }
+
+        Ok(recorder.finish())
+    }
+}
+
+/// The tile that's off, if the board reflects along `axis` but for one tile
+fn find_smudge(board: &Board, axis: Axis) -> Option<Point> {
+    let mut different = axis
+        .mirrored(board.rows(), board.columns())
+        .into_iter()
+        .filter(|&(a, b)| board[a] != board[b]);
+
+    match (different.next(), different.next()) {
+        (Some((smudge, _)), None) => Some(smudge),
+        _ => None,
+    }
+}
+
+/// The patterns one under another, each with the lines next to its (part 1)
+/// line of reflection and its smudge marked
+impl Render for Day13 {
+    fn render(boards: &Self::Input) -> Result<Picture, Error> {
+        let columns = boards.iter().map(Grid::columns).max().unwrap_or(0);
+
+        let mut rows = vec![];
+        for board in boards {
+            if !rows.is_empty() {
+                rows.push(vec![Cell::BLANK; columns]);
+            }
+
+            let axes = Axis::all(board.rows(), board.columns());
+            let clean = axes.iter().find(|&&axis| differences(board, axis) == 0);
+            let smudge = axes.iter().find_map(|&axis| find_smudge(board, axis));
+
+            for (i, row) in board.iter_rows().enumerate() {
+                let mut cells: Vec<Cell> = row
+                    .iter()
+                    .enumerate()
+                    .map(|(j, tile)| {
+                        let point = Point::new(i, j);
+                        let style = if smudge == Some(point) {
+                            Style::Smudge
+                        } else if clean.is_some_and(|axis| axis.beside(point)) {
+                            Style::Reflection
+                        } else if *tile == Tile::Rock {
+                            Style::Plain
+                        } else {
+                            Style::Empty
+                        };
+                        Cell::new(tile.symbol(), style)
+                    })
+                    .collect();
+                cells.resize(columns, Cell::BLANK);
+                rows.push(cells);
+            }
+        }
+
+        Ok(Grid::from_rows(rows).expect("All the rows are padded to the same length"))
+    }
+}
+
+/// Representative of the tile's group of tiles that have to be the same
+fn find(groups: &mut [usize], mut tile: usize) -> usize {
+    while groups[tile] != tile {
+        groups[tile] = groups[groups[tile]];
+        tile = groups[tile];
+    }
+
+    tile
+}
+
+/// A pattern with a reflection along `clean` and, off by a single smudge,
+/// along `smudged`, or `None` if the smudged pair is forced to be the same
+fn plant_reflections(
+    rng: &mut GenRng,
+    (rows, columns): (usize, usize),
+    clean: Axis,
+    smudged: Axis,
+) -> Option<Board> {
+    let index = |point: Point| point.i * columns + point.j;
+    let mut groups: Vec<usize> = (0..rows * columns).collect();
+
+    let mut pairs = smudged.mirrored(rows, columns);
+    let smudge = pairs.swap_remove(rng.gen_range(0..pairs.len()));
+    pairs.extend(clean.mirrored(rows, columns));
+    for (a, b) in pairs {
+        let (a, b) = (find(&mut groups, index(a)), find(&mut groups, index(b)));
+        groups[a] = b;
+    }
+
+    let (a, b) = (
+        find(&mut groups, index(smudge.0)),
+        find(&mut groups, index(smudge.1)),
+    );
+    if a == b {
+        return None;
+    }
+
+    let mut tiles: Vec<Tile> = (0..rows * columns)
+        .map(|_| if rng.gen() { Tile::Rock } else { Tile::Ash })
+        .collect();
+    tiles[b] = tiles[a].inverse();
+
+    let rows = (0..rows)
+        .map(|i| {
+            (0..columns)
+                .map(|j| tiles[find(&mut groups, i * columns + j)])
+                .collect()
+        })
+        .collect();
+    Some(Grid::from_rows(rows).expect("All the rows have the same length"))
+}
+
+/// `count` patterns of up to `length` rows and columns (at least 5). Each
+/// one reflects along a single line and, once its smudge is cleaned, along
+/// a single other one.
+impl Generate for Day13 {
+    fn generate(rng: &mut GenRng, size: Size) -> String {
+        let max = size.length.max(5);
+        let min = (max / 2).max(5);
+
+        let mut patterns = Vec::with_capacity(size.count);
+        while patterns.len() < size.count {
+            let (rows, columns) = (rng.gen_range(min..=max), rng.gen_range(min..=max));
+            let axes = Axis::all(rows, columns);
+
+            let clean = axes[rng.gen_range(0..axes.len())];
+            let smudged = axes[rng.gen_range(0..axes.len())];
+            if clean == smudged {
+                continue;
+            }
+            let Some(board) = plant_reflections(rng, (rows, columns), clean, smudged) else {
+                continue;
+            };
+
+            // other lines might have ended up (almost) reflecting by accident
+            let lines: Vec<_> = axes
+                .iter()
+                .map(|&axis| (axis, differences(&board, axis)))
+                .filter(|&(_, differences)| differences <= 1)
+                .collect();
+            if lines == [(clean, 0), (smudged, 1)] || lines == [(smudged, 1), (clean, 0)] {
+                patterns.push(board);
+            }
+        }
+
+        patterns
+            .iter()
+            .map(|board| {
+                board
+                    .iter_rows()
+                    .map(|row| row.iter().map(Tile::to_string).collect::<String>() + "\n")
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn tile() -> impl Strategy<Value = Tile> {
+        prop_oneof![Just(Tile::Ash), Just(Tile::Rock)]
+    }
+
+    /// Boards with a planted reflection reaching the bottom edge: some rows,
+    /// then a mirrored block of rows. Transposed half of the time, so that the
+    /// reflection is between columns instead.
+    fn board() -> impl Strategy<Value = Board> {
+        (2..=9usize, 0..=6usize, 1..=4usize, any::<bool>()).prop_flat_map(
+            |(columns, rows, half, transposed)| {
+                let row = prop::collection::vec(tile(), columns);
+                prop::collection::vec(row, rows + half).prop_map(move |mut rows| {
+                    let mirrored: Vec<_> =
+                        rows[rows.len() - half..].iter().rev().cloned().collect();
+                    rows.extend(mirrored);
+                    let board = Grid::from_rows(rows).unwrap();
+                    if transposed {
+                        board.transposed()
+                    } else {
+                        board
+                    }
+                })
+            },
+        )
+    }
+
+    /// Whether every row on one side of the `(top, bottom)` axis mirrors the
+    /// row on the other side, for as long as there are rows on both.
+    fn reflects(board: &Board, (top, bottom): (usize, usize)) -> bool {
+        (0..=top)
+            .rev()
+            .zip(bottom..board.rows())
+            .all(|(p, q)| board.row(p) == board.row(q))
+    }
+
+    proptest! {
+        #[test]
+        fn reflecting_about_the_axis_yields_the_board(board in board()) {
+            let row_axis = find_row_axis(&board, &mut Recorder::off());
+            let col_axis = find_col_axis(&board, &mut Recorder::off());
+            prop_assert!(row_axis.is_some() || col_axis.is_some());
+
+            if let Some(axis) = row_axis {
+                prop_assert!(reflects(&board, axis));
+            }
+            if let Some(axis) = col_axis {
+                prop_assert!(reflects(&board.transposed(), axis));
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::{Day, Error, ParseError, Part};
+use day13::Day13;
+
+const DAY: Day = Day::of::<Day13>();
+
+#[test]
+fn position_of_an_unknown_tile() {
+    let input = "#.##\n..#.\n\n#..#\n#.x#\n";
+
+    assert_eq!(
+        DAY.solve(input, Part::One).unwrap_err(),
+        Error::Parse(ParseError::new(5, 3, "x", "`#` or `.`"))
+    );
+}
@@ -0,0 +1,10 @@
+#!/bin/sh
+# Seeds the corpus of every fuzz target with the examples of its day.
+set -e
+cd "$(dirname "$0")"
+
+for dir in ../day[0-9][0-9]; do
+    day=$(basename "$dir")
+    mkdir -p "corpus/$day"
+    cp "$dir"/test*.txt "corpus/$day/"
+done
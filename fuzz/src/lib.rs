@@ -0,0 +1,24 @@
+//! Fuzz targets, one for every day: whatever the input, parsing and solving
+//! it can only fail with an error, never panic.
+//!
+//! Needs `cargo-fuzz` and a nightly compiler:
+//!
+//! ```sh
+//! fuzz/seed-corpus.sh
+//! cargo +nightly fuzz run day08
+//! ```
+
+use aoc::find_day;
+use aoc_common::Part;
+
+/// Parses `input` as an input of `day` and, if it's valid, solves both parts.
+pub fn solve(day: u8, input: &str) {
+    let day = find_day(day).expect("Every fuzz target is for a solved day");
+    let Ok(parsed) = day.parse(input) else {
+        return;
+    };
+
+    for part in Part::ALL {
+        let _ = parsed.solve(part);
+    }
+}